@@ -0,0 +1,98 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use crate::export::OutputFormat;
+
+/// 스캔 디렉토리에서 자동으로 찾는 설정 파일 이름
+pub const CONFIG_FILE_NAME: &str = "aintent.toml";
+
+/// `aintent.toml` 설정. 모든 항목은 선택이며 CLI 플래그가 우선한다.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub llm: LlmSection,
+    pub scan: ScanSection,
+    pub suppress: SuppressSection,
+    pub output: OutputSection,
+    pub app_links: AppLinksSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LlmSection {
+    pub url: Option<String>,
+    pub key: Option<String>,
+    pub model: Option<String>,
+    /// LLM에 보낼 Intent 관련 코드 앞뒤 줄 수
+    pub context_lines: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScanSection {
+    pub package: Option<String>,
+    /// 매니페스트 경로(스캔 디렉토리 기준)에 적용할 glob. 비어 있으면 모두 포함
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    /// test/androidTest 소스 세트의 매니페스트도 분석
+    pub include_tests: Option<bool>,
+    /// 매니페스트를 병합할 빌드 변형
+    pub variant: Option<String>,
+    /// build/intermediates의 병합된 매니페스트 사용
+    pub merged_manifests: Option<bool>,
+    /// 분석할 컴포넌트 타입 (activity, service, receiver, provider). 비어 있으면 모두
+    pub component_types: Vec<String>,
+    pub max_permission_level: Option<String>,
+    pub alive_only: Option<bool>,
+    pub no_shared_userid: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppLinksSection {
+    /// `/.well-known/assetlinks.json` 사본 (파일 또는 host별 디렉토리)
+    pub assetlinks: Option<String>,
+    /// 앱 서명 인증서의 SHA-256 지문
+    pub signing_cert_sha256: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SuppressSection {
+    /// 결과에서 제외할 컴포넌트 이름 (glob 가능)
+    pub components: Vec<String>,
+    /// 보고하지 않을 규칙 ID
+    pub rules: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OutputSection {
+    pub format: Option<OutputFormat>,
+    pub output: Option<String>,
+    pub pretty_xml: Option<bool>,
+    pub log_level: Option<String>,
+}
+
+/// `--config`로 지정한 파일, 없으면 스캔 디렉토리의 `aintent.toml`을 읽는다.
+///
+/// 지정한 파일이 없으면 오류이고, 자동 탐색에서 파일이 없으면 `None`을 반환한다.
+pub fn load_config(explicit: Option<&Path>, scan_dir: &Path) -> Result<Option<(Config, PathBuf)>> {
+    let path = match explicit {
+        Some(path) => path.to_path_buf(),
+        None => {
+            let path = scan_dir.join(CONFIG_FILE_NAME);
+            if !path.is_file() {
+                return Ok(None);
+            }
+            path
+        }
+    };
+
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+    let config: Config = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
+    Ok(Some((config, path)))
+}
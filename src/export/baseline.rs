@@ -0,0 +1,257 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use anyhow::{bail, Context, Result};
+use crate::export::json::{self, ComponentReport, ScanReport, SCHEMA_VERSION};
+use crate::export::qualified_class_name;
+use crate::manifest::component::IntentFilter;
+use crate::manifest::uri::{self, PatternKind};
+
+/// 새로운 무방비 공격 표면이 발견됐을 때의 종료 코드 (CI 게이트용)
+pub const NEW_SURFACE_EXIT_CODE: i32 = 3;
+
+/// 기준선과 현재 스캔 사이의 차이
+#[derive(Debug, Default)]
+pub struct BaselineDiff {
+    pub added: Vec<ComponentChange>,
+    pub removed: Vec<ComponentChange>,
+    pub changed: Vec<ComponentChange>,
+}
+
+/// 컴포넌트 하나의 변경 내용
+#[derive(Debug, Default)]
+pub struct ComponentChange {
+    /// package/class 형태의 식별자
+    pub id: String,
+    pub component_type: String,
+    /// 현재 스캔에서 권한 없이 노출되어 있는지
+    pub unprotected: bool,
+    pub added_permissions: Vec<String>,
+    pub removed_permissions: Vec<String>,
+    pub added_intent_filters: Vec<String>,
+    pub added_deep_link_hosts: Vec<String>,
+    pub added_extras: Vec<String>,
+}
+
+impl ComponentChange {
+    fn is_empty(&self) -> bool {
+        self.added_permissions.is_empty()
+            && self.removed_permissions.is_empty()
+            && self.added_intent_filters.is_empty()
+            && self.added_deep_link_hosts.is_empty()
+            && self.added_extras.is_empty()
+    }
+
+    /// 이 변경으로 권한 없이 접근 가능한 표면이 늘었는지
+    fn adds_surface(&self) -> bool {
+        self.unprotected
+            && (!self.removed_permissions.is_empty()
+                || !self.added_intent_filters.is_empty()
+                || !self.added_deep_link_hosts.is_empty()
+                || !self.added_extras.is_empty())
+    }
+}
+
+impl BaselineDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// 권한 없이 새로 노출된 컴포넌트나, 무방비 컴포넌트에 새 진입점이 생겼는지
+    pub fn has_new_unprotected_surface(&self) -> bool {
+        self.added.iter().any(|c| c.unprotected)
+            || self.changed.iter().any(ComponentChange::adds_surface)
+    }
+}
+
+/// 스캔 결과를 이후 비교에 쓸 기준선 파일(JSON 스캔 보고서)로 저장한다.
+pub fn save_baseline(report: &ScanReport, path: &Path) -> Result<()> {
+    let mut file = fs::File::create(path)
+        .with_context(|| format!("Failed to create baseline: {}", path.display()))?;
+    json::write_json(report, &mut file)
+}
+
+/// `--save-baseline` 또는 `--format json`으로 저장한 기준선을 읽는다.
+pub fn load_baseline(path: &Path) -> Result<ScanReport> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read baseline: {}", path.display()))?;
+    let report: ScanReport = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse baseline: {}", path.display()))?;
+    if report.schema_version > SCHEMA_VERSION {
+        bail!(
+            "Baseline {} uses schema version {}, newer than supported version {}",
+            path.display(),
+            report.schema_version,
+            SCHEMA_VERSION
+        );
+    }
+    Ok(report)
+}
+
+/// exported 컴포넌트를 기준으로 두 스캔을 비교한다.
+pub fn diff_reports(baseline: &ScanReport, current: &ScanReport) -> BaselineDiff {
+    let old = exported_components(baseline);
+    let new = exported_components(current);
+    let mut diff = BaselineDiff::default();
+
+    for (id, report) in &new {
+        match old.get(id) {
+            None => {
+                let mut change = new_change(id, report);
+                change.added_permissions = permission_names(report).into_iter().collect();
+                change.added_intent_filters = filter_signatures(&report.component.intent_filters).into_iter().collect();
+                change.added_deep_link_hosts = report.component.data_hosts.iter().cloned().collect();
+                change.added_extras = extra_names(report).into_iter().collect();
+                diff.added.push(change);
+            }
+            Some(previous) => {
+                let mut change = new_change(id, report);
+                change.added_permissions = added(&permission_names(previous), &permission_names(report));
+                change.removed_permissions = added(&permission_names(report), &permission_names(previous));
+                change.added_intent_filters = added(
+                    &filter_signatures(&previous.component.intent_filters),
+                    &filter_signatures(&report.component.intent_filters),
+                );
+                change.added_deep_link_hosts = added(&previous.component.data_hosts, &report.component.data_hosts);
+                change.added_extras = added(&extra_names(previous), &extra_names(report));
+                if !change.is_empty() {
+                    diff.changed.push(change);
+                }
+            }
+        }
+    }
+
+    for (id, report) in &old {
+        if !new.contains_key(id) {
+            diff.removed.push(new_change(id, report));
+        }
+    }
+
+    diff
+}
+
+/// 차이를 사람이 읽을 수 있는 형태로 출력한다.
+pub fn write_diff(diff: &BaselineDiff, out: &mut impl Write) -> Result<()> {
+    if diff.is_empty() {
+        writeln!(out, "No attack surface changes since baseline")?;
+        return Ok(());
+    }
+
+    writeln!(
+        out,
+        "Attack surface changes since baseline: {} added, {} removed, {} changed",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len()
+    )?;
+    for change in &diff.added {
+        let marker = if change.unprotected { " (no permission)" } else { "" };
+        writeln!(out, "+ {} {}{}", change.component_type, change.id, marker)?;
+        write_details(change, out)?;
+    }
+    for change in &diff.removed {
+        writeln!(out, "- {} {}", change.component_type, change.id)?;
+    }
+    for change in &diff.changed {
+        let marker = if change.unprotected { " (no permission)" } else { "" };
+        writeln!(out, "~ {} {}{}", change.component_type, change.id, marker)?;
+        write_details(change, out)?;
+    }
+    Ok(())
+}
+
+fn write_details(change: &ComponentChange, out: &mut impl Write) -> Result<()> {
+    let sections = [
+        ("+ permission", &change.added_permissions),
+        ("- permission", &change.removed_permissions),
+        ("+ intent-filter", &change.added_intent_filters),
+        ("+ deep-link host", &change.added_deep_link_hosts),
+        ("+ extra", &change.added_extras),
+    ];
+    for (label, values) in sections {
+        for value in values {
+            writeln!(out, "    {} {}", label, value)?;
+        }
+    }
+    Ok(())
+}
+
+fn exported_components(report: &ScanReport) -> BTreeMap<String, &ComponentReport> {
+    report.components.iter()
+        .filter(|c| c.component.is_effectively_exported())
+        .map(|c| (format!("{}/{}", c.component.package, qualified_class_name(&c.component)), c))
+        .collect()
+}
+
+fn new_change(id: &str, report: &ComponentReport) -> ComponentChange {
+    ComponentChange {
+        id: id.to_string(),
+        component_type: report.component.component_type.clone(),
+        unprotected: report.permissions.is_empty(),
+        ..Default::default()
+    }
+}
+
+fn permission_names(report: &ComponentReport) -> BTreeSet<String> {
+    report.permissions.iter().map(|p| p.name.clone()).collect()
+}
+
+/// -a/-c/-d/-t가 아닌 파라미터(extra) 이름
+fn extra_names(report: &ComponentReport) -> BTreeSet<String> {
+    report.parameters.iter()
+        .filter(|p| !matches!(p.flag.as_str(), "-a" | "-c" | "-d" | "-t"))
+        .map(|p| p.name.clone())
+        .collect()
+}
+
+/// intent-filter를 순서와 위치에 상관없이 비교할 수 있는 문자열로 바꾼다.
+fn filter_signatures(filters: &[IntentFilter]) -> BTreeSet<String> {
+    filters.iter()
+        .map(|filter| {
+            let mut actions = filter.actions.clone();
+            actions.sort();
+            let mut categories = filter.categories.clone();
+            categories.sort();
+            let mut data: Vec<String> = filter.data.iter()
+                .map(|d| {
+                    let mut uri = format!(
+                        "{}://{}{}{}",
+                        d.scheme.as_deref().unwrap_or("*"),
+                        d.host.as_deref().unwrap_or("*"),
+                        d.port.as_deref().map(|p| format!(":{}", p)).unwrap_or_default(),
+                        d.path.as_deref().unwrap_or("")
+                    );
+                    // path 외의 path/ssp/fragment 조건은 종류와 함께 덧붙인다
+                    let patterns = [uri::path_patterns(d), uri::ssp_patterns(d), uri::fragment_patterns(d)];
+                    for (target, patterns) in ["path", "ssp", "fragment"].iter().zip(patterns) {
+                        for pattern in patterns.iter().filter(|p| *target != "path" || p.kind != PatternKind::Literal) {
+                            uri.push_str(&format!(" {}{}={}", target, pattern.kind.attribute_suffix(), pattern.value));
+                        }
+                    }
+                    if let Some(mime_type) = &d.mime_type {
+                        uri.push_str(&format!(" ({})", mime_type));
+                    }
+                    uri
+                })
+                .collect();
+            data.sort();
+
+            let mut parts = Vec::new();
+            if !actions.is_empty() {
+                parts.push(format!("actions=[{}]", actions.join(", ")));
+            }
+            if !categories.is_empty() {
+                parts.push(format!("categories=[{}]", categories.join(", ")));
+            }
+            if !data.is_empty() {
+                parts.push(format!("data=[{}]", data.join(", ")));
+            }
+            parts.join(" ")
+        })
+        .collect()
+}
+
+fn added(before: &BTreeSet<String>, after: &BTreeSet<String>) -> Vec<String> {
+    after.difference(before).cloned().collect()
+}
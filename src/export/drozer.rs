@@ -0,0 +1,89 @@
+use std::fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::export::{ComponentAnalysis, qualified_class_name};
+use crate::utils::source::IntentParameter;
+
+/// 분석된 컴포넌트를 drozer `run` 명령어 스크립트로 변환해 파일로 저장한다.
+///
+/// `drozer console connect < <file>` 형태로 실행할 수 있도록 한 줄에 한 명령어만 쓴다.
+pub fn write_drozer_script(analyses: &[ComponentAnalysis], output: &Path) -> Result<usize> {
+    let commands: Vec<String> = analyses.iter()
+        .map(drozer_command)
+        .collect();
+
+    let mut script = commands.join("\n");
+    script.push('\n');
+    fs::write(output, script)
+        .with_context(|| format!("Failed to write drozer script: {}", output.display()))?;
+    Ok(commands.len())
+}
+
+/// 컴포넌트 타입에 맞는 drozer 명령어를 만든다.
+pub fn drozer_command(analysis: &ComponentAnalysis) -> String {
+    let component = &analysis.component;
+    let class_name = qualified_class_name(component);
+
+    if component.component_type == "provider" {
+        let uri = analysis.content_uri();
+        return format!("run app.provider.query {}", quote(&uri));
+    }
+
+    let mut command = match component.component_type.as_str() {
+        // 동적 receiver는 action으로만 받는다
+        "receiver" if component.dynamic.is_some() => "run app.broadcast.send".to_string(),
+        "receiver" => format!("run app.broadcast.send --component {} {}", component.package, class_name),
+        // app.service.send는 Messenger 기반 서비스에 Message를 전달한다
+        "service" => format!("run app.service.send {} {} --msg 1 0 0", component.package, class_name),
+        _ => format!("run app.activity.start --component {} {}", component.package, class_name),
+    };
+
+    // app.service.send는 action/category/data를 받지 않는다
+    if component.component_type != "service" {
+        if let Some(action) = analysis.action() {
+            command.push_str(&format!(" --action {}", quote(&action)));
+        }
+        let categories = analysis.categories();
+        if !categories.is_empty() {
+            let categories: Vec<String> = categories.iter().map(|c| quote(c)).collect();
+            command.push_str(&format!(" --category {}", categories.join(" ")));
+        }
+        if let Some(uri) = analysis.data_uri() {
+            command.push_str(&format!(" --data-uri {}", quote(&uri)));
+        }
+        if let Some(mime_type) = analysis.mime_type() {
+            command.push_str(&format!(" --mimetype {}", quote(&mime_type)));
+        }
+    }
+
+    for extra in analysis.typed_extras() {
+        let (type_, value) = drozer_extra(&extra);
+        command.push_str(&format!(" --extra {} {} {}", type_, quote(&extra.name), quote(&value)));
+    }
+
+    if component.component_type == "service" {
+        command.push_str(" --bundle-as-obj");
+    }
+
+    command
+}
+
+/// `IntentParameter` 타입을 drozer `--extra` 타입으로 변환한다.
+fn drozer_extra(param: &IntentParameter) -> (&'static str, String) {
+    let value = param.value.trim().trim_matches('"').to_string();
+    match param.type_.as_str() {
+        "int" => ("integer", value.parse::<i32>().unwrap_or(0).to_string()),
+        "long" => ("long", value.trim_end_matches(['l', 'L']).parse::<i64>().unwrap_or(0).to_string()),
+        "float" => ("float", value.trim_end_matches(['f', 'F', 'd', 'D']).parse::<f32>().unwrap_or(0.0).to_string()),
+        "boolean" => ("boolean", (value == "true").to_string()),
+        _ => ("string", value),
+    }
+}
+
+/// drozer 콘솔은 shlex로 인자를 나누므로 공백/따옴표가 있는 값만 감싼다.
+fn quote(value: &str) -> String {
+    if !value.is_empty() && !value.contains(|c: char| c.is_whitespace() || c == '"' || c == '\'') {
+        return value.to_string();
+    }
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,195 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tracing::warn;
+use crate::export::{normalize_type, qualified_class_name, sanitize_file_name};
+use crate::manifest::Component;
+use crate::utils::source::IntentParameter;
+
+/// Frida 스크립트가 출력하는 한 줄의 JSON 레코드
+#[derive(Debug, Deserialize)]
+struct FridaRecord {
+    component: String,
+    key: String,
+    #[serde(rename = "type")]
+    type_: String,
+    #[serde(default)]
+    default: Option<String>,
+}
+
+/// 패키지별로 Frida 훅 스크립트(`<package>.js`)를 생성하고 생성된 파일 목록을 반환한다.
+///
+/// 스크립트는 `Intent.get*Extra`와 `Bundle.get*` 호출 중 분석된 컴포넌트 클래스의
+/// 스택에서 발생한 것만 JSON 한 줄로 기록하며, 그 로그는 [`import_frida_results`]로 다시 읽는다.
+pub fn write_frida_scripts(components: &[Component], output_dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut packages: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for component in components {
+        // 동적 receiver의 이름(`Outer$receiver1`)은 실제 클래스가 아니라서 스택에서 찾을 수 없다
+        if component.dynamic.is_some() {
+            continue;
+        }
+        let classes = packages.entry(component.package.as_str()).or_default();
+        let class_name = qualified_class_name(component);
+        if !classes.contains(&class_name) {
+            classes.push(class_name);
+        }
+    }
+
+    fs::create_dir_all(output_dir)
+        .with_context(|| format!("Failed to create Frida output directory: {}", output_dir.display()))?;
+
+    let mut written = Vec::new();
+    for (package, classes) in packages {
+        let path = output_dir.join(format!("{}.js", sanitize_file_name(package)));
+        fs::write(&path, render_script(package, &classes))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Frida 로그 파일을 읽어 컴포넌트 클래스 이름별 extra 목록으로 변환한다.
+///
+/// JSON으로 파싱되지 않는 줄(Frida 배너, 다른 로그 등)은 무시한다.
+pub fn import_frida_results(path: &Path) -> Result<HashMap<String, Vec<IntentParameter>>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read Frida results: {}", path.display()))?;
+
+    let mut results: HashMap<String, Vec<IntentParameter>> = HashMap::new();
+    for line in contents.lines() {
+        let Some(start) = line.find('{') else {
+            continue;
+        };
+        let record: FridaRecord = match serde_json::from_str(&line[start..]) {
+            Ok(record) => record,
+            Err(_) => continue,
+        };
+
+        let type_ = normalize_type(&record.type_).to_string();
+        let params = results.entry(record.component).or_default();
+        if params.iter().any(|p| p.name == record.key && p.type_ == type_) {
+            continue;
+        }
+        params.push(IntentParameter {
+            name: record.key,
+            value: record.default.unwrap_or_else(|| type_.clone()),
+            type_,
+        });
+    }
+
+    if results.is_empty() {
+        warn!("No Frida records found in {}", path.display());
+    }
+    Ok(results)
+}
+
+/// 런타임에 확인된 extra 중 정적 분석 결과에 없는 키만 골라낸다.
+pub fn missing_runtime_extras(extras: &[IntentParameter], runtime: &[IntentParameter]) -> Vec<IntentParameter> {
+    runtime.iter()
+        .filter(|param| !extras.iter().any(|p| p.name == param.name))
+        .cloned()
+        .collect()
+}
+
+fn render_script(package: &str, classes: &[String]) -> String {
+    let components = serde_json::to_string_pretty(classes).unwrap_or_else(|_| "[]".to_string());
+    FRIDA_TEMPLATE
+        .replace("{package}", package)
+        .replace("{components}", &components)
+}
+
+const FRIDA_TEMPLATE: &str = r#"// Generated by aintent for {package}
+//
+// Usage:
+//   frida -U -f {package} -l <this file> -o frida.log
+//   aintent -d <project> --frida-results frida.log
+//
+// Each observed extra is printed as one JSON line:
+//   {"source":"frida","component":"...","key":"...","type":"...","default":...}
+'use strict';
+
+const COMPONENTS = {components};
+
+const INTENT_GETTERS = [
+    'getStringExtra', 'getCharSequenceExtra', 'getIntExtra', 'getLongExtra',
+    'getShortExtra', 'getByteExtra', 'getCharExtra', 'getFloatExtra',
+    'getDoubleExtra', 'getBooleanExtra', 'getStringArrayExtra',
+    'getStringArrayListExtra', 'getIntArrayExtra', 'getParcelableExtra',
+    'getParcelableArrayExtra', 'getParcelableArrayListExtra',
+    'getSerializableExtra', 'getBundleExtra',
+];
+
+const BASE_BUNDLE_GETTERS = [
+    'getString', 'getInt', 'getLong', 'getDouble', 'getBoolean',
+    'getStringArray', 'getIntArray',
+];
+
+const BUNDLE_GETTERS = [
+    'getCharSequence', 'getFloat', 'getShort', 'getByte', 'getChar',
+    'getStringArrayList', 'getParcelable', 'getParcelableArray',
+    'getParcelableArrayList', 'getSerializable', 'getBundle',
+];
+
+const seen = {};
+
+function findComponent() {
+    const frames = Java.use('java.lang.Thread').currentThread().getStackTrace();
+    for (let i = 0; i < frames.length; i++) {
+        const className = frames[i].getClassName().split('$')[0];
+        if (COMPONENTS.indexOf(className) !== -1) {
+            return className;
+        }
+    }
+    return null;
+}
+
+function typeOf(method) {
+    return method.replace(/^get/, '').replace(/Extra$/, '').toLowerCase();
+}
+
+function report(method, args) {
+    if (args.length === 0 || args[0] === null) {
+        return;
+    }
+    const component = findComponent();
+    if (component === null) {
+        return;
+    }
+    const record = {
+        source: 'frida',
+        component: component,
+        key: String(args[0]),
+        type: typeOf(method),
+        default: args.length > 1 && args[1] !== null ? String(args[1]) : null,
+    };
+    const id = record.component + '|' + record.key + '|' + record.type;
+    if (seen[id]) {
+        return;
+    }
+    seen[id] = true;
+    console.log(JSON.stringify(record));
+}
+
+function hookAll(className, methods) {
+    const clazz = Java.use(className);
+    methods.forEach(function (method) {
+        if (clazz[method] === undefined) {
+            return;
+        }
+        clazz[method].overloads.forEach(function (overload) {
+            overload.implementation = function () {
+                report(method, arguments);
+                return overload.apply(this, arguments);
+            };
+        });
+    });
+}
+
+Java.perform(function () {
+    hookAll('android.content.Intent', INTENT_GETTERS);
+    hookAll('android.os.BaseBundle', BASE_BUNDLE_GETTERS);
+    hookAll('android.os.Bundle', BUNDLE_GETTERS);
+});
+"#;
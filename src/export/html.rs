@@ -0,0 +1,304 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+use anyhow::Result;
+use crate::export::json::{ComponentReport, ScanReport};
+use crate::manifest::component::IntentFilter;
+use crate::manifest::xml_span::escape;
+
+const COMPONENT_TYPES: [&str; 4] = ["activity", "service", "receiver", "provider"];
+const SOURCE_CONTEXT_LINES: usize = 2;
+const MAX_SOURCE_SNIPPET_LINES: usize = 60;
+
+/// 패키지별 공격 표면 요약을 외부 리소스 없는 단일 HTML 파일로 출력한다.
+pub fn write_html(report: &ScanReport, out: &mut impl Write) -> Result<()> {
+    let mut packages: BTreeMap<&str, Vec<&ComponentReport>> = BTreeMap::new();
+    for component in &report.components {
+        packages.entry(component.component.package.as_str()).or_default().push(component);
+    }
+
+    write!(out, "{}", HTML_HEAD)?;
+    writeln!(out, "<h1>aintent attack surface report</h1>")?;
+    writeln!(
+        out,
+        "<p class=\"meta\">{} {} &middot; schema {} &middot; {} packages, {} components</p>",
+        escape(&report.tool.name),
+        escape(&report.tool.version),
+        report.schema_version,
+        packages.len(),
+        report.components.len()
+    )?;
+
+    writeln!(out, "<nav><ul>")?;
+    for package in packages.keys() {
+        writeln!(out, "<li><a href=\"#{}\">{}</a></li>", anchor(package), escape(package))?;
+    }
+    writeln!(out, "</ul></nav>")?;
+
+    for (package, components) in &packages {
+        write_package(package, components, out)?;
+    }
+
+    // 컴포넌트에 속하지 않는 소스 코드의 발견 사항
+    if !report.source_findings.is_empty() {
+        writeln!(out, "<section class=\"package\" id=\"source-findings\">")?;
+        writeln!(out, "<h2>Source findings</h2><ul>")?;
+        for finding in &report.source_findings {
+            writeln!(
+                out,
+                "<li><span class=\"level {s}\">{s}</span> <code>{}</code> {} <span class=\"meta\">{}:{}</span></li>",
+                escape(&finding.rule_id),
+                escape(&finding.message),
+                escape(&finding.location.path.display().to_string()),
+                finding.location.line,
+                s = finding.severity.as_str()
+            )?;
+        }
+        writeln!(out, "</ul></section>")?;
+    }
+
+    write!(out, "{}", HTML_TAIL)?;
+    Ok(())
+}
+
+fn write_package(package: &str, components: &[&ComponentReport], out: &mut impl Write) -> Result<()> {
+    writeln!(out, "<section class=\"package\" id=\"{}\">", anchor(package))?;
+    writeln!(out, "<h2>{}</h2>", escape(package))?;
+
+    // 타입별 exported 컴포넌트 수
+    writeln!(out, "<table class=\"summary\"><tr><th>Type</th><th>Exported</th><th>Total</th></tr>")?;
+    for component_type in COMPONENT_TYPES {
+        let of_type: Vec<_> = components.iter()
+            .filter(|c| c.component.component_type == component_type)
+            .collect();
+        let exported = of_type.iter().filter(|c| c.component.is_effectively_exported()).count();
+        writeln!(out, "<tr><td>{}</td><td>{}</td><td>{}</td></tr>", component_type, exported, of_type.len())?;
+    }
+    writeln!(out, "</table>")?;
+
+    for component in components {
+        write_component(component, out)?;
+    }
+    writeln!(out, "</section>")?;
+    Ok(())
+}
+
+fn write_component(report: &ComponentReport, out: &mut impl Write) -> Result<()> {
+    let component = &report.component;
+    let open = if component.is_effectively_exported() { " open" } else { "" };
+    writeln!(out, "<details class=\"component\"{}>", open)?;
+    writeln!(
+        out,
+        "<summary><span class=\"badge {t}\">{t}</span> {name}{exported}{dynamic}</summary>",
+        t = escape(&component.component_type),
+        name = escape(&component.name),
+        exported = if component.is_effectively_exported() { " <span class=\"badge exported\">exported</span>" } else { "" },
+        dynamic = if component.dynamic.is_some() { " <span class=\"badge\">registerReceiver</span>" } else { "" }
+    )?;
+    writeln!(
+        out,
+        "<p class=\"meta\">{}:{}:{}</p>",
+        escape(&component.manifest_path.display().to_string()),
+        component.manifest_line,
+        component.manifest_column
+    )?;
+
+    // 권한
+    writeln!(out, "<h4>Permissions</h4>")?;
+    if report.permissions.is_empty() {
+        writeln!(out, "<p class=\"none\">No permission required</p>")?;
+    } else {
+        writeln!(out, "<table><tr><th>Permission</th><th>Protection level</th><th>Declared on</th></tr>")?;
+        for permission in &report.permissions {
+            writeln!(
+                out,
+                "<tr><td>{}</td><td><span class=\"level {l}\">{l}</span></td><td>{}</td></tr>",
+                escape(&permission.name),
+                escape(&permission.declared_on),
+                l = escape(&permission.protection_level)
+            )?;
+        }
+        writeln!(out, "</table>")?;
+    }
+    if !report.permission_guards.is_empty() {
+        writeln!(out, "<p>Checked in code:</p><ul>")?;
+        for guard in &report.permission_guards {
+            writeln!(out, "<li><code>{}</code> {}</li>", escape(&guard.code), escape(&guard.describe()))?;
+        }
+        writeln!(out, "</ul>")?;
+    }
+
+    // intent-filter
+    if !component.intent_filters.is_empty() {
+        writeln!(out, "<h4>Intent filters</h4><ul>")?;
+        for filter in &component.intent_filters {
+            writeln!(out, "<li>{}</li>", describe_filter(filter))?;
+        }
+        writeln!(out, "</ul>")?;
+    }
+
+    if !report.example_uris.is_empty() {
+        writeln!(out, "<h4>Deep links</h4><ul>")?;
+        for link in &report.example_uris {
+            writeln!(out, "<li><code>{}</code></li>", escape(link))?;
+        }
+        writeln!(out, "</ul>")?;
+    }
+
+    // 파라미터
+    if !report.parameters.is_empty() {
+        writeln!(out, "<h4>Parameters</h4>")?;
+        writeln!(out, "<table><tr><th>Name</th><th>Type</th><th>Value</th><th>Flag</th><th>Provenance</th></tr>")?;
+        for param in &report.parameters {
+            writeln!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td><code>{}</code></td><td>{}</td></tr>",
+                escape(&param.name),
+                escape(&param.type_),
+                escape(&param.value),
+                escape(&param.flag),
+                param.provenance.as_str()
+            )?;
+        }
+        writeln!(out, "</table>")?;
+    }
+
+    // 명령어
+    writeln!(out, "<h4>Commands</h4>")?;
+    for command in &report.commands {
+        writeln!(
+            out,
+            "<div class=\"command\"><pre><code>{}</code></pre><button type=\"button\" onclick=\"copyCommand(this)\">Copy</button></div>{}",
+            escape(&command.command),
+            command.purpose.as_ref()
+                .map(|purpose| format!("<p class=\"purpose\">{}</p>", escape(purpose)))
+                .unwrap_or_default()
+        )?;
+    }
+
+    if !report.findings.is_empty() {
+        writeln!(out, "<h4>Findings</h4><ul>")?;
+        for finding in &report.findings {
+            writeln!(
+                out,
+                "<li><span class=\"level {s}\">{s}</span> <code>{}</code> {}</li>",
+                escape(&finding.rule_id),
+                escape(&finding.message),
+                s = finding.severity.as_str()
+            )?;
+        }
+        writeln!(out, "</ul>")?;
+    }
+
+    if let Some(xml) = &component.xml_element {
+        writeln!(out, "<h4>Manifest</h4><pre class=\"snippet\">{}</pre>", escape(xml))?;
+    }
+
+    if let Some(source_file) = &report.source_file {
+        if let Some(snippet) = source_snippet(source_file) {
+            writeln!(
+                out,
+                "<h4>Source <span class=\"meta\">{}</span></h4><pre class=\"snippet\">{}</pre>",
+                escape(&source_file.display().to_string()),
+                escape(&snippet)
+            )?;
+        }
+    }
+
+    writeln!(out, "</details>")?;
+    Ok(())
+}
+
+fn describe_filter(filter: &IntentFilter) -> String {
+    let mut parts = Vec::new();
+    if !filter.actions.is_empty() {
+        parts.push(format!("actions: <code>{}</code>", escape(&filter.actions.join(", "))));
+    }
+    if !filter.categories.is_empty() {
+        parts.push(format!("categories: <code>{}</code>", escape(&filter.categories.join(", "))));
+    }
+    let mime_types: Vec<&str> = filter.data.iter()
+        .filter_map(|d| d.mime_type.as_deref())
+        .collect();
+    if !mime_types.is_empty() {
+        parts.push(format!("mime types: <code>{}</code>", escape(&mime_types.join(", "))));
+    }
+    parts.push(format!("<span class=\"meta\">line {}</span>", filter.line));
+    parts.join(" &middot; ")
+}
+
+/// intent 관련 코드 주변 줄만 모아 보여준다.
+fn source_snippet(path: &Path) -> Option<String> {
+    let source = std::fs::read_to_string(path).ok()?;
+    let lines: Vec<&str> = source.lines().collect();
+    let mut include = vec![false; lines.len()];
+    for (i, line) in lines.iter().enumerate() {
+        if line.contains("Extra(") || line.contains("getData(") || line.contains("getIntent(") || line.contains("getExtras(") {
+            let start = i.saturating_sub(SOURCE_CONTEXT_LINES);
+            let end = (i + SOURCE_CONTEXT_LINES + 1).min(lines.len());
+            include[start..end].iter_mut().for_each(|v| *v = true);
+        }
+    }
+
+    let mut snippet = String::new();
+    let mut previous = None;
+    for (i, line) in lines.iter().enumerate().filter(|(i, _)| include[*i]).take(MAX_SOURCE_SNIPPET_LINES) {
+        if previous.is_some_and(|p: usize| p + 1 != i) {
+            snippet.push_str("     ...\n");
+        }
+        snippet.push_str(&format!("{:>4} {}\n", i + 1, line));
+        previous = Some(i);
+    }
+    (!snippet.is_empty()).then_some(snippet)
+}
+
+fn anchor(package: &str) -> String {
+    format!("pkg-{}", package.replace(|c: char| !c.is_ascii_alphanumeric(), "-"))
+}
+
+const HTML_HEAD: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>aintent report</title>
+<style>
+body { font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; margin: 2em auto; max-width: 1100px; color: #222; }
+h1 { border-bottom: 2px solid #333; }
+h2 { margin-top: 2em; border-bottom: 1px solid #ccc; }
+h4 { margin-bottom: .3em; }
+nav ul { columns: 2; }
+table { border-collapse: collapse; margin: .5em 0; }
+th, td { border: 1px solid #ddd; padding: 4px 8px; text-align: left; font-size: 14px; }
+th { background: #f4f4f4; }
+code, pre { font-family: Menlo, Consolas, monospace; font-size: 13px; }
+pre { background: #f7f7f7; padding: .6em; overflow-x: auto; margin: 0; }
+details.component { border: 1px solid #ddd; border-radius: 4px; margin: .8em 0; padding: .5em 1em; }
+summary { cursor: pointer; font-weight: bold; }
+.meta { color: #777; font-size: 13px; font-weight: normal; }
+.none { color: #b00; }
+.badge { display: inline-block; border-radius: 3px; padding: 0 6px; font-size: 12px; background: #e0e0e0; }
+.badge.exported { background: #f8d7da; color: #842029; }
+.level { display: inline-block; border-radius: 3px; padding: 0 6px; font-size: 12px; background: #e0e0e0; }
+.level.normal, .level.warning { background: #fff3cd; }
+.level.dangerous, .level.error { background: #f8d7da; }
+.level.signature, .level.note { background: #d1e7dd; }
+.command { display: flex; gap: .5em; align-items: flex-start; margin: .3em 0; }
+.command pre { flex: 1; }
+.snippet { max-height: 400px; }
+</style>
+</head>
+<body>
+"#;
+
+const HTML_TAIL: &str = r#"<script>
+function copyCommand(button) {
+    var text = button.parentElement.querySelector('code').innerText;
+    navigator.clipboard.writeText(text).then(function () {
+        button.innerText = 'Copied';
+        setTimeout(function () { button.innerText = 'Copy'; }, 1500);
+    });
+}
+</script>
+</body>
+</html>
+"#;
@@ -0,0 +1,189 @@
+use std::io::Write;
+use std::path::PathBuf;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use crate::export::{ComponentAnalysis, Provenance};
+use crate::manifest::{uri, Component};
+use crate::rules::Finding;
+use crate::rules::redirection::IntentRedirection;
+use crate::utils::guards::PermissionGuard;
+use crate::utils::source::adb_extra_flag;
+
+/// JSON 출력 스키마 버전. 필드를 제거하거나 의미를 바꿀 때만 올린다.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// `--format json` 출력의 최상위 문서
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanReport {
+    pub schema_version: u32,
+    pub tool: ToolInfo,
+    pub components: Vec<ComponentReport>,
+    /// 컴포넌트에 속하지 않는 소스 코드의 발견 사항 (PendingIntent 등)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub source_findings: Vec<Finding>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolInfo {
+    pub name: String,
+    pub version: String,
+}
+
+/// 컴포넌트 하나에 대한 결과. `--format jsonl`에서는 한 줄에 하나씩 출력된다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentReport {
+    pub component: Component,
+    pub permissions: Vec<PermissionReport>,
+    pub parameters: Vec<ParameterReport>,
+    pub source_file: Option<PathBuf>,
+    pub commands: Vec<CommandReport>,
+    #[serde(default)]
+    pub findings: Vec<Finding>,
+    /// intent-filter의 data 조건에 맞는 예시 URI
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub example_uris: Vec<String>,
+    /// extra의 intent가 시작/전송되는 경로와 중첩 intent
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub intent_redirections: Vec<IntentRedirection>,
+    /// provider의 authorities와 UriMatcher 경로로 만든 URI
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub content_uris: Vec<String>,
+    /// 진입점 코드에서 찾은 호출자 권한 확인
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permission_guards: Vec<PermissionGuard>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermissionReport {
+    pub name: String,
+    pub protection_level: String,
+    /// component 또는 intent-filter
+    pub declared_on: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterReport {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub value: String,
+    /// 생성된 adb 명령어에서 사용되는 플래그 (-a, -d, --es, ...)
+    pub flag: String,
+    pub provenance: Provenance,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandReport {
+    pub command: String,
+    /// 테스트 값을 넣은 변형 명령어가 시험하는 경로. 기본 명령어는 없음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+}
+
+/// JSON Lines의 한 줄: 각 줄이 독립적으로 해석될 수 있도록 스키마 버전을 함께 쓴다.
+#[derive(Serialize)]
+struct ComponentLine<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    report: &'a ComponentReport,
+}
+
+/// 컴포넌트 줄 뒤에 이어지는 소스 발견 사항 한 줄
+#[derive(Serialize)]
+struct SourceFindingLine<'a> {
+    schema_version: u32,
+    source_finding: &'a Finding,
+}
+
+pub fn build_report(analyses: &[ComponentAnalysis], source_findings: &[Finding]) -> ScanReport {
+    ScanReport {
+        schema_version: SCHEMA_VERSION,
+        tool: ToolInfo {
+            name: env!("CARGO_PKG_NAME").to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        },
+        components: analyses.iter().map(component_report).collect(),
+        source_findings: source_findings.to_vec(),
+    }
+}
+
+pub fn write_json(report: &ScanReport, out: &mut impl Write) -> Result<()> {
+    serde_json::to_writer_pretty(&mut *out, report)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+pub fn write_jsonl(report: &ScanReport, out: &mut impl Write) -> Result<()> {
+    for component in &report.components {
+        let line = ComponentLine {
+            schema_version: report.schema_version,
+            report: component,
+        };
+        serde_json::to_writer(&mut *out, &line)?;
+        writeln!(out)?;
+    }
+    for finding in &report.source_findings {
+        let line = SourceFindingLine {
+            schema_version: report.schema_version,
+            source_finding: finding,
+        };
+        serde_json::to_writer(&mut *out, &line)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+fn component_report(analysis: &ComponentAnalysis) -> ComponentReport {
+    let component = &analysis.component;
+
+    let permissions = component.required_permissions().into_iter()
+        .map(|(name, declared_on)| PermissionReport {
+            name: name.to_string(),
+            protection_level: component.protection_level(name).to_string(),
+            declared_on: declared_on.to_string(),
+        })
+        .collect();
+
+    let mut parameters: Vec<ParameterReport> = analysis.intent_params.iter()
+        .map(|p| ParameterReport {
+            name: p.name.clone(),
+            type_: p.param_type.clone(),
+            value: p.value.clone(),
+            flag: p.flag.clone(),
+            provenance: analysis.params_provenance,
+        })
+        .collect();
+    let extras = analysis.extras.iter()
+        .map(|p| (p, Provenance::TreeSitter))
+        .chain(analysis.runtime_extras.iter().map(|p| (p, Provenance::Frida)));
+    for (param, provenance) in extras {
+        parameters.push(ParameterReport {
+            name: param.name.clone(),
+            type_: param.type_.clone(),
+            value: param.value.clone(),
+            flag: adb_extra_flag(&param.type_).to_string(),
+            provenance,
+        });
+    }
+
+    ComponentReport {
+        component: component.clone(),
+        permissions,
+        parameters,
+        source_file: analysis.source_file.clone(),
+        commands: std::iter::once(CommandReport {
+            command: analysis.command.clone(),
+            purpose: None,
+        })
+        .chain(analysis.command_variants.iter().map(|variant| CommandReport {
+            command: variant.command.clone(),
+            purpose: Some(variant.purpose.clone()),
+        }))
+        .collect(),
+        findings: analysis.findings.clone(),
+        example_uris: uri::example_uris(component),
+        intent_redirections: analysis.redirections.clone(),
+        content_uris: analysis.content_uris.clone(),
+        permission_guards: analysis.permission_guards.clone(),
+    }
+}
@@ -0,0 +1,207 @@
+pub mod baseline;
+pub mod drozer;
+pub mod frida;
+pub mod html;
+pub mod json;
+pub mod poc;
+pub mod sarif;
+pub mod text;
+
+use std::io::Write;
+use std::path::PathBuf;
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use crate::manifest::Component;
+use crate::llm::analyzer::IntentParameter as FlagParameter;
+use crate::rules::Finding;
+use crate::rules::redirection::IntentRedirection;
+use crate::utils::guards::PermissionGuard;
+use crate::utils::source::IntentParameter;
+
+pub use drozer::write_drozer_script;
+pub use frida::{import_frida_results, missing_runtime_extras, write_frida_scripts};
+pub use poc::write_poc_project;
+
+/// 결과 출력 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Jsonl,
+    Sarif,
+    Html,
+}
+
+/// 파라미터를 어디서 얻었는지
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Provenance {
+    Manifest,
+    TreeSitter,
+    Llm,
+    Frida,
+}
+
+impl Provenance {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Provenance::Manifest => "manifest",
+            Provenance::TreeSitter => "tree-sitter",
+            Provenance::Llm => "llm",
+            Provenance::Frida => "frida",
+        }
+    }
+}
+
+/// 하나의 컴포넌트에 대한 분석 결과 (출력/내보내기 공통 입력)
+#[derive(Debug, Clone)]
+pub struct ComponentAnalysis {
+    pub component: Component,
+    /// 소스 코드(tree-sitter)에서 찾은 extra
+    pub extras: Vec<IntentParameter>,
+    /// Frida 로그에서만 확인된 extra
+    pub runtime_extras: Vec<IntentParameter>,
+    /// 매니페스트 기본값 또는 LLM 분석으로 얻은 -a/-c/-d/-t/-e 파라미터
+    pub intent_params: Vec<FlagParameter>,
+    pub params_provenance: Provenance,
+    pub command: String,
+    pub source_file: Option<PathBuf>,
+    pub findings: Vec<Finding>,
+    /// extra의 intent가 시작/전송되는 경로 (AINT008)
+    pub redirections: Vec<IntentRedirection>,
+    /// 테스트 값을 넣은 추가 명령어 (AINT009)
+    pub command_variants: Vec<CommandVariant>,
+    /// provider의 authorities와 UriMatcher 경로로 만든 URI
+    pub content_uris: Vec<String>,
+    /// 진입점 코드에서 찾은 호출자 권한 확인
+    pub permission_guards: Vec<PermissionGuard>,
+}
+
+/// 기본 명령어에서 extra나 data 하나를 바꾼 명령어
+#[derive(Debug, Clone)]
+pub struct CommandVariant {
+    pub command: String,
+    /// 어떤 경로를 시험하는지
+    pub purpose: String,
+}
+
+/// 결과 출력 설정
+#[derive(Debug, Clone)]
+pub struct OutputOptions {
+    pub format: OutputFormat,
+    /// 스캔한 디렉토리 (SARIF 상대 경로 기준)
+    pub scan_root: PathBuf,
+    /// 원본 대신 정규화된 컴포넌트 XML 표시
+    pub pretty_xml: bool,
+}
+
+/// 분석 결과를 지정한 형식으로 출력한다.
+///
+/// `source_findings`는 컴포넌트에 속하지 않는 소스 코드의 발견 사항 (PendingIntent 등)이다.
+pub fn write_results(
+    analyses: &[ComponentAnalysis],
+    source_findings: &[Finding],
+    options: &OutputOptions,
+    out: &mut impl Write,
+) -> Result<()> {
+    match options.format {
+        OutputFormat::Text => text::write_text(analyses, source_findings, options, out),
+        OutputFormat::Json => json::write_json(&json::build_report(analyses, source_findings), out),
+        OutputFormat::Jsonl => json::write_jsonl(&json::build_report(analyses, source_findings), out),
+        OutputFormat::Sarif => sarif::write_sarif(analyses, source_findings, &options.scan_root, out),
+        OutputFormat::Html => html::write_html(&json::build_report(analyses, source_findings), out),
+    }
+}
+
+impl ComponentAnalysis {
+    /// 소스 분석과 LLM 분석에서 나온 extra를 하나의 타입 목록으로 합친다.
+    pub fn typed_extras(&self) -> Vec<IntentParameter> {
+        let mut result: Vec<IntentParameter> = Vec::new();
+        let llm_extras = self.intent_params.iter()
+            .filter(|p| p.flag == "-e")
+            .map(|p| IntentParameter {
+                name: p.name.clone(),
+                value: p.value.clone(),
+                type_: normalize_type(&p.param_type).to_string(),
+            });
+
+        for param in self.extras.iter().chain(&self.runtime_extras).cloned().chain(llm_extras) {
+            if param.type_ == "uri" || result.iter().any(|p| p.name == param.name) {
+                continue;
+            }
+            result.push(param);
+        }
+        result
+    }
+
+    /// -a 파라미터 또는 매니페스트의 첫 번째 action
+    pub fn action(&self) -> Option<String> {
+        self.flag_value("-a")
+            .or_else(|| self.component.actions.iter().next().cloned())
+    }
+
+    pub fn categories(&self) -> Vec<String> {
+        let mut categories: Vec<String> = self.intent_params.iter()
+            .filter(|p| p.flag == "-c")
+            .map(|p| p.value.clone())
+            .collect();
+        if categories.is_empty() {
+            categories = self.component.categories.iter().cloned().collect();
+        }
+        categories
+    }
+
+    pub fn data_uri(&self) -> Option<String> {
+        self.flag_value("-d")
+    }
+
+    /// provider에 보낼 URI: UriMatcher에서 찾은 URI, -d 파라미터, `content://<package>/` 순
+    pub fn content_uri(&self) -> String {
+        self.content_uris.first().cloned()
+            .or_else(|| self.data_uri())
+            .unwrap_or_else(|| format!("content://{}/", self.component.package))
+    }
+
+    pub fn mime_type(&self) -> Option<String> {
+        self.flag_value("-t")
+    }
+
+    fn flag_value(&self, flag: &str) -> Option<String> {
+        self.intent_params.iter()
+            .find(|p| p.flag == flag && !p.value.is_empty())
+            .map(|p| p.value.clone())
+    }
+}
+
+/// LLM이 돌려주는 타입 이름(String, Integer, ...)을 소스 분석 타입 이름으로 변환
+pub fn normalize_type(param_type: &str) -> &'static str {
+    match param_type.to_lowercase().as_str() {
+        "string" | "charsequence" => "string",
+        "int" | "integer" | "short" | "byte" => "int",
+        "long" => "long",
+        "float" | "double" => "float",
+        "boolean" | "bool" => "boolean",
+        "uri" => "uri",
+        _ => "unknown",
+    }
+}
+
+/// 매니페스트의 패키지를 기준으로 컴포넌트의 완전한 클래스 이름을 구한다.
+pub fn qualified_class_name(component: &Component) -> String {
+    if component.name.starts_with('.') {
+        format!("{}{}", component.package, component.name)
+    } else if !component.name.contains('.') {
+        format!("{}.{}", component.package, component.name)
+    } else {
+        component.name.clone()
+    }
+}
+
+/// 파일/디렉토리 이름으로 사용할 수 있도록 영숫자 이외의 문자를 '_'로 바꾼다.
+pub fn sanitize_file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
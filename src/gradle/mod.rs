@@ -0,0 +1,238 @@
+pub mod build_file;
+pub mod settings;
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// settings.gradle(.kts) 하나로 정의되는 Gradle 프로젝트
+#[derive(Debug, Clone)]
+pub struct GradleProject {
+    pub root: PathBuf,
+    pub modules: Vec<GradleModule>,
+}
+
+/// Gradle 모듈 (`:app`, `:feature:login`, ...)
+#[derive(Debug, Clone)]
+pub struct GradleModule {
+    pub path: String,
+    pub dir: PathBuf,
+    pub kind: ModuleKind,
+    pub namespace: Option<String>,
+    pub application_id: Option<String>,
+    pub target_sdk: Option<u32>,
+    /// 소스 세트 이름(main, debug, free, ...)별 매니페스트와 소스 디렉토리
+    pub source_sets: BTreeMap<String, SourceSet>,
+    /// `project(':lib')`로 의존하는 모듈 경로
+    pub dependencies: Vec<String>,
+    /// manifestPlaceholders로 정의한 `${name}` 값
+    pub manifest_placeholders: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleKind {
+    Application,
+    Library,
+    Other,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SourceSet {
+    pub manifest: PathBuf,
+    pub source_dirs: Vec<PathBuf>,
+}
+
+const SETTINGS_FILES: [&str; 2] = ["settings.gradle", "settings.gradle.kts"];
+const BUILD_FILES: [&str; 2] = ["build.gradle", "build.gradle.kts"];
+
+/// `dir` 아래의 모든 settings.gradle(.kts)을 찾아 프로젝트 모델을 만든다.
+pub fn find_projects(dir: &Path) -> Vec<GradleProject> {
+    walkdir::WalkDir::new(dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| {
+            let name = e.file_name().to_string_lossy();
+            e.depth() == 0 || !(name.starts_with('.') || name == "build")
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && SETTINGS_FILES.iter().any(|f| e.file_name() == *f))
+        .filter_map(|e| {
+            let root = e.path().parent()?.to_path_buf();
+            match std::fs::read_to_string(e.path()) {
+                Ok(content) => Some(load_project(root, &content)),
+                Err(err) => {
+                    warn!("Failed to read {}: {}", e.path().display(), err);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+fn load_project(root: PathBuf, settings_content: &str) -> GradleProject {
+    let modules = settings::parse_settings(settings_content)
+        .into_iter()
+        .map(|(path, dir)| load_module(path, root.join(dir)))
+        .collect();
+    GradleProject { root, modules }
+}
+
+fn load_module(path: String, dir: PathBuf) -> GradleModule {
+    let build_file = BUILD_FILES.iter()
+        .map(|name| dir.join(name))
+        .find(|p| p.is_file())
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .map(|content| build_file::parse_build_file(&content))
+        .unwrap_or_default();
+
+    // 관례상의 소스 세트 (src/<name>/) 위에 sourceSets 블록의 설정을 덧붙인다
+    let mut source_sets = BTreeMap::new();
+    if let Ok(entries) = std::fs::read_dir(dir.join("src")) {
+        for entry in entries.filter_map(Result::ok).filter(|e| e.path().is_dir()) {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            source_sets.insert(name.clone(), conventional_source_set(&dir, &name));
+        }
+    }
+    for (name, configured) in build_file.source_sets {
+        let source_set = source_sets.entry(name.clone())
+            .or_insert_with(|| conventional_source_set(&dir, &name));
+        if let Some(manifest) = configured.manifest {
+            source_set.manifest = dir.join(manifest);
+        }
+        for source_dir in configured.source_dirs {
+            let source_dir = dir.join(source_dir);
+            if !source_set.source_dirs.contains(&source_dir) {
+                source_set.source_dirs.push(source_dir);
+            }
+        }
+    }
+
+    GradleModule {
+        path,
+        dir,
+        kind: build_file.kind,
+        namespace: build_file.namespace,
+        application_id: build_file.application_id,
+        target_sdk: build_file.target_sdk,
+        source_sets,
+        dependencies: build_file.dependencies,
+        manifest_placeholders: build_file.manifest_placeholders,
+    }
+}
+
+fn conventional_source_set(module_dir: &Path, name: &str) -> SourceSet {
+    let base = module_dir.join("src").join(name);
+    SourceSet {
+        manifest: base.join("AndroidManifest.xml"),
+        source_dirs: vec![base.join("java"), base.join("kotlin")],
+    }
+}
+
+impl GradleProject {
+    pub fn module(&self, path: &str) -> Option<&GradleModule> {
+        self.modules.iter().find(|m| m.path == path)
+    }
+
+    /// 모듈과 (전이적으로) 의존하는 모듈을 선언 순서대로 나열한다. 첫 번째는 모듈 자신이다.
+    pub fn dependency_order<'a>(&'a self, module: &'a GradleModule) -> Vec<&'a GradleModule> {
+        let mut order = Vec::new();
+        let mut visited = BTreeSet::new();
+        let mut queue = VecDeque::from([module]);
+        while let Some(module) = queue.pop_front() {
+            if !visited.insert(module.path.clone()) {
+                continue;
+            }
+            order.push(module);
+            queue.extend(module.dependencies.iter().filter_map(|d| self.module(d)));
+        }
+        order
+    }
+
+    /// 모듈 자신과 (전이적으로) 의존하는 모듈의 모든 소스 디렉토리. 존재하는 디렉토리만 돌려준다.
+    pub fn source_roots(&self, module: &GradleModule) -> Vec<PathBuf> {
+        let mut visited = BTreeSet::new();
+        let mut roots = Vec::new();
+        self.collect_source_roots(module, &mut visited, &mut roots);
+        roots
+    }
+
+    fn collect_source_roots(&self, module: &GradleModule, visited: &mut BTreeSet<String>, roots: &mut Vec<PathBuf>) {
+        if !visited.insert(module.path.clone()) {
+            return;
+        }
+        let generated = module.dir.join("build").join("generated");
+        let dirs = module.source_sets.values()
+            .flat_map(|s| s.source_dirs.iter().cloned())
+            .chain(std::iter::once(generated));
+        for dir in dirs {
+            if dir.is_dir() && !roots.contains(&dir) {
+                roots.push(dir);
+            }
+        }
+        for dependency in &module.dependencies {
+            if let Some(dependency) = self.module(dependency) {
+                self.collect_source_roots(dependency, visited, roots);
+            }
+        }
+    }
+
+    /// 소스 세트의 매니페스트 중 실제로 있는 것 (관례와 다른 위치로 지정된 것 포함)
+    pub fn manifest_files(&self) -> Vec<PathBuf> {
+        self.modules.iter()
+            .flat_map(|m| m.source_sets.values())
+            .map(|s| s.manifest.clone())
+            .filter(|p| p.is_file())
+            .collect()
+    }
+}
+
+/// 매니페스트 파일이 속한 프로젝트와 모듈. sourceSets에 지정된 매니페스트가 우선이고,
+/// 그 외에는 매니페스트를 포함하는 가장 깊은 모듈 디렉토리를 고른다.
+pub fn module_for_manifest<'a>(projects: &'a [GradleProject], manifest_path: &Path) -> Option<(&'a GradleProject, &'a GradleModule)> {
+    let modules = projects.iter()
+        .flat_map(|p| p.modules.iter().map(move |m| (p, m)));
+    let declared = modules.clone()
+        .find(|(_, m)| m.source_set_for_manifest(manifest_path).is_some());
+    declared.or_else(|| {
+        modules
+            .filter(|(_, m)| manifest_path.starts_with(&m.dir))
+            .max_by_key(|(_, m)| m.dir.components().count())
+    })
+}
+
+impl GradleModule {
+    /// 이 매니페스트를 사용하는 소스 세트 이름
+    pub fn source_set_for_manifest(&self, manifest_path: &Path) -> Option<&str> {
+        self.source_sets.iter()
+            .find(|(_, s)| s.manifest == manifest_path)
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+/// 작은따옴표 또는 큰따옴표로 감싼 문자열을 순서대로 꺼낸다.
+pub(crate) fn quoted_strings(text: &str) -> Vec<String> {
+    let mut strings = Vec::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '"' || c == '\'' {
+            let value: String = chars.by_ref().take_while(|&next| next != c).collect();
+            strings.push(value);
+        }
+    }
+    strings
+}
+
+/// 줄 끝의 `//` 주석을 제거한다. 따옴표 안의 `//`(URL 등)는 유지한다.
+pub(crate) fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let bytes = line.as_bytes();
+    for (i, &b) in bytes.iter().enumerate() {
+        match (quote, b) {
+            (None, b'"') | (None, b'\'') => quote = Some(b),
+            (Some(q), b) if q == b => quote = None,
+            (None, b'/') if bytes.get(i + 1) == Some(&b'/') => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
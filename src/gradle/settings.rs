@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use crate::gradle::{quoted_strings, strip_comment};
+
+/// settings.gradle(.kts)에서 포함된 모듈 경로와 모듈 디렉토리(프로젝트 루트 기준)를 읽는다.
+///
+/// `include ':app', ':lib'`, `include(":app")`, 여러 줄에 걸친 `include(...)`와
+/// `project(':lib').projectDir = file('libs/lib')` 형태를 지원한다.
+pub fn parse_settings(content: &str) -> Vec<(String, PathBuf)> {
+    let mut modules: Vec<String> = Vec::new();
+    let mut project_dirs = BTreeMap::new();
+    let mut in_include = false;
+
+    for line in content.lines() {
+        let line = strip_comment(line).trim();
+
+        if in_include || (line.starts_with("include") && !line.starts_with("includeBuild")) {
+            for module in quoted_strings(line) {
+                let module = normalize_module_path(&module);
+                if !modules.contains(&module) {
+                    modules.push(module);
+                }
+            }
+            // `include(` 뒤로 닫는 괄호가 다음 줄 이후에 나오는 경우
+            in_include = if in_include { !line.contains(')') } else { line.contains('(') && !line.contains(')') };
+            continue;
+        }
+
+        if line.contains("project(") && line.contains("projectDir") {
+            let strings = quoted_strings(line);
+            if let (Some(module), Some(dir)) = (strings.first(), strings.last()) {
+                if strings.len() > 1 {
+                    project_dirs.insert(normalize_module_path(module), PathBuf::from(dir));
+                }
+            }
+        }
+    }
+
+    modules.into_iter()
+        .map(|module| {
+            let dir = project_dirs.get(&module)
+                .cloned()
+                .unwrap_or_else(|| module.trim_start_matches(':').split(':').collect());
+            (module, dir)
+        })
+        .collect()
+}
+
+fn normalize_module_path(module: &str) -> String {
+    if module.starts_with(':') {
+        module.to_string()
+    } else {
+        format!(":{}", module)
+    }
+}
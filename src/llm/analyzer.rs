@@ -3,17 +3,19 @@ use std::io::{self, BufRead, BufReader};
 use std::path::PathBuf;
 use std::fmt;
 use reqwest::Client;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use anyhow::{Result, Context};
 use tracing::{info, error, warn};
 use crate::manifest::Component;
+use crate::manifest::uri::FilterData;
 use super::config::LLMConfig;
 use walkdir;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IntentParameter {
     pub name: String,
-    #[allow(dead_code)]
+    #[serde(rename = "type")]
     pub param_type: String,
     pub value: String,
     pub flag: String,  // -a, -c, -e 등의 플래그
@@ -43,7 +45,7 @@ pub async fn analyze_intent(
     let lines = read_source_file(source_file)?;
     
     // Intent 관련 코드 추출
-    let context = extract_intent_context(&lines)?;
+    let context = extract_intent_context(&lines, config.context_lines)?;
     
     // LLM API 호출
     let analysis = call_llm_api(&context, config).await?;
@@ -73,7 +75,7 @@ fn read_source_file(source_file: &str) -> Result<Vec<String>> {
     Ok(lines)
 }
 
-fn extract_intent_context(lines: &[String]) -> Result<String> {
+fn extract_intent_context(lines: &[String], context_size: usize) -> Result<String> {
     let mut has_intent = false;
     let mut context_lines = Vec::new();
     let mut in_intent_block = false;
@@ -126,11 +128,11 @@ fn extract_intent_context(lines: &[String]) -> Result<String> {
             block_depth = 0;
             last_intent_line = i;
             
-            // 이전 컨텍스트 라인 추가 (최대 5줄)
-            let start = i.saturating_sub(5);
-            for j in start..i {
-                if !context_lines.contains(&lines[j]) {
-                    context_lines.push(lines[j].clone());
+            // 이전 컨텍스트 라인 추가 (최대 context_size줄)
+            let start = i.saturating_sub(context_size);
+            for prev in &lines[start..i] {
+                if !context_lines.contains(prev) {
+                    context_lines.push(prev.clone());
                 }
             }
         }
@@ -147,11 +149,11 @@ fn extract_intent_context(lines: &[String]) -> Result<String> {
                 block_depth -= 1;
                 if block_depth == 0 {
                     in_intent_block = false;
-                    // 이후 컨텍스트 라인 추가 (최대 5줄)
-                    let end = (i + 6).min(lines.len());
-                    for j in (i + 1)..end {
-                        if !context_lines.contains(&lines[j]) {
-                            context_lines.push(lines[j].clone());
+                    // 이후 컨텍스트 라인 추가 (최대 context_size줄)
+                    let end = (i + context_size + 1).min(lines.len());
+                    for next in &lines[(i + 1)..end] {
+                        if !context_lines.contains(next) {
+                            context_lines.push(next.clone());
                         }
                     }
                 }
@@ -165,10 +167,10 @@ fn extract_intent_context(lines: &[String]) -> Result<String> {
     }
 
     // 마지막 Intent 라인 이후의 컨텍스트 추가
-    let end = (last_intent_line + 6).min(lines.len());
-    for i in (last_intent_line + 1)..end {
-        if !context_lines.contains(&lines[i]) {
-            context_lines.push(lines[i].clone());
+    let end = (last_intent_line + context_size + 1).min(lines.len());
+    for next in &lines[(last_intent_line + 1)..end] {
+        if !context_lines.contains(next) {
+            context_lines.push(next.clone());
         }
     }
 
@@ -276,7 +278,7 @@ Code to analyze:
     });
 
     let response = client
-        .post(&format!("{}/chat/completions", config.api_url))
+        .post(format!("{}/chat/completions", config.api_url))
         .headers(headers)
         .json(&request_body)
         .send()
@@ -486,11 +488,9 @@ pub fn find_source_file(component: &Component, _source_dir: &str) -> Result<Path
         if let Ok(file) = File::open(&file_path) {
             let reader = BufReader::new(file);
             let mut content = String::new();
-            for line in reader.lines() {
-                if let Ok(line) = line {
-                    content.push_str(&line);
-                    content.push('\n');
-                }
+            for line in reader.lines().map_while(Result::ok) {
+                content.push_str(&line);
+                content.push('\n');
             }
 
             // 다양한 패턴으로 매칭 시도
@@ -523,7 +523,7 @@ async fn analyze_with_llm(context: &str, config: &LLMConfig) -> Result<IntentAna
     );
     
     let response = client
-        .post(&format!("{}/chat/completions", config.api_url))
+        .post(format!("{}/chat/completions", config.api_url))
         .json(&json!({
             "model": config.model_type,
             "messages": [
@@ -640,8 +640,18 @@ async fn analyze_with_llm(context: &str, config: &LLMConfig) -> Result<IntentAna
 pub fn generate_basic_params(component: &Component) -> Vec<IntentParameter> {
     let mut params = Vec::new();
 
+    // Android는 intent를 필터 하나와 비교하므로 action, category, data를 같은 필터에서 고른다
+    let filter = component.intent_filters.iter()
+        .find(|f| !f.actions.is_empty())
+        .or(component.intent_filters.first());
+    let (action, category) = match filter {
+        Some(filter) => (filter.actions.first(), filter.categories.first()),
+        None => (component.actions.iter().next(), component.categories.iter().next()),
+    };
+    let filter_data = filter.map(FilterData::from_filter).unwrap_or_default();
+
     // Add action if available
-    if let Some(action) = component.actions.iter().next() {
+    if let Some(action) = action {
         params.push(IntentParameter {
             name: "action".to_string(),
             param_type: "String".to_string(),
@@ -651,7 +661,7 @@ pub fn generate_basic_params(component: &Component) -> Vec<IntentParameter> {
     }
 
     // Add category if available
-    if let Some(category) = component.categories.iter().next() {
+    if let Some(category) = category {
         params.push(IntentParameter {
             name: "category".to_string(),
             param_type: "String".to_string(),
@@ -660,19 +670,8 @@ pub fn generate_basic_params(component: &Component) -> Vec<IntentParameter> {
         });
     }
 
-    // Add data URI if scheme and host are available
-    if !component.data_schemes.is_empty() && !component.data_hosts.is_empty() {
-        let scheme = component.data_schemes.iter().next().unwrap();
-        let host = component.data_hosts.iter().next().unwrap();
-        let empty_path = String::new();
-        let path = component.data_paths.iter().next().unwrap_or(&empty_path);
-        
-        let uri = if !path.is_empty() {
-            format!("{}://{}{}", scheme, host, path)
-        } else {
-            format!("{}://{}", scheme, host)
-        };
-
+    // 필터의 data 조건에 맞는 첫 번째 예시 URI
+    if let Some(uri) = filter_data.example_uris().into_iter().next() {
         params.push(IntentParameter {
             name: "data".to_string(),
             param_type: "Uri".to_string(),
@@ -682,7 +681,7 @@ pub fn generate_basic_params(component: &Component) -> Vec<IntentParameter> {
     }
 
     // Add MIME type if available
-    if let Some(mime_type) = component.data_mime_types.iter().next() {
+    if let Some(mime_type) = filter_data.mime_types.first() {
         params.push(IntentParameter {
             name: "type".to_string(),
             param_type: "String".to_string(),
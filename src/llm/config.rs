@@ -37,14 +37,14 @@ impl LLMConfig {
             api_url,
             api_key,
             model_type,
-            context_lines: 10, // Default value
+            context_lines: 5, // Default value
         }
     }
 }
 
 pub async fn fetch_available_models(api_url: &str, api_key: Option<&str>) -> Result<Vec<String>, Box<dyn std::error::Error>> {
     let client = Client::new();
-    let mut request = client.get(&format!("{}/models", api_url));
+    let mut request = client.get(format!("{}/models", api_url));
     
     if let Some(key) = api_key {
         request = request.header("Authorization", format!("Bearer {}", key));
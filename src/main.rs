@@ -1,20 +1,32 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use clap::Parser;
-use crate::manifest::{Component, find_manifest_files, parse_manifest};
-use crate::permissions::get_permission_protection_level;
-use crate::utils::adb::ADBCommand;
-use crate::utils::source::{find_source_file, parse_intent_parameters, intent_parameters_to_adb_args};
+use crate::manifest::{Component, DiscoveryOptions, find_manifest_files, parse_manifest};
+use crate::manifest::merger::{merge_project_components, MergeOptions};
+use crate::manifest::resources::resolve_components;
+use crate::utils::adb::{content_command, ADBCommand};
+use crate::utils::dataflow::TaintFlow;
+use crate::utils::provider::{analyze_provider_source, ProviderSource};
+use crate::utils::source::{find_source_file, parse_intent_parameters, intent_parameters_to_adb_args, IntentParameter, SourceFileCache};
 use crate::llm::{LLMConfig, fetch_available_models};
+use crate::export::{CommandVariant, ComponentAnalysis, OutputFormat, OutputOptions, Provenance};
+use crate::rules::Finding;
+use crate::rules::webview::{percent_encode, PayloadSlot, WebViewFlow};
+use std::collections::HashMap;
+use std::io::Write;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use anyhow::{Result, Context};
-use tracing::{info, error, warn, Level};
+use tracing::{debug, info, error, warn, Level};
 use tracing_subscriber::FmtSubscriber;
 mod manifest;
 mod permissions;
 mod utils;
 mod llm;
+mod export;
+mod rules;
+mod config;
+mod gradle;
 
 /// Android 프로젝트에서 AndroidManifest.xml 파일을 검색하고 exported 컴포넌트를 파싱하는 프로그램
 #[derive(Parser, Debug)]
@@ -28,18 +40,26 @@ struct Args {
     #[arg(short, long)]
     package: Option<String>,
 
-    /// 최대 권한 보호 수준 (normal, dangerous, signature)
-    #[arg(short, long, default_value = "signature")]
-    max_permission_level: String,
+    /// 최대 권한 보호 수준 (normal, dangerous, signature). 지정하지 않으면 거르지 않는다
+    #[arg(short, long)]
+    max_permission_level: Option<String>,
 
     /// 현재 설치된 패키지의 컴포넌트만 표시
-    #[arg(short, long)]
+    #[arg(short, long, overrides_with = "no_alive_only")]
     alive_only: bool,
 
+    /// 설정 파일의 alive_only를 끔
+    #[arg(long, overrides_with = "alive_only")]
+    no_alive_only: bool,
+
     /// sharedUserId가 있는 컴포넌트 제외
-    #[arg(long)]
+    #[arg(long, overrides_with = "shared_userid")]
     no_shared_userid: bool,
 
+    /// 설정 파일의 no_shared_userid를 끔
+    #[arg(long, overrides_with = "no_shared_userid")]
+    shared_userid: bool,
+
     /// LLM API URL (로컬 LLM의 경우 기본값: http://localhost:1234/v1)
     #[arg(long)]
     llm_url: Option<String>,
@@ -52,12 +72,107 @@ struct Args {
     #[arg(long)]
     llm_model: Option<String>,
 
-    /// 로그 레벨
-    #[arg(long, default_value = "info")]
-    log_level: String,
+    /// LLM에 보낼 Intent 관련 코드 앞뒤 줄 수 (기본값: 5)
+    #[arg(long)]
+    llm_context_lines: Option<usize>,
+
+    /// 로그 레벨. 기본값: info
+    #[arg(long)]
+    log_level: Option<String>,
+
+    /// 설정 파일 (기본값: 검색 디렉토리의 aintent.toml)
+    #[arg(long)]
+    config: Option<String>,
+
+    /// 포함할 매니페스트 경로 glob (검색 디렉토리 기준, 반복 가능)
+    #[arg(long)]
+    include: Vec<String>,
+
+    /// 제외할 매니페스트 경로 glob (검색 디렉토리 기준, 반복 가능)
+    #[arg(long)]
+    exclude: Vec<String>,
+
+    /// test/androidTest 소스 세트의 매니페스트도 분석
+    #[arg(long, overrides_with = "no_include_tests")]
+    include_tests: bool,
+
+    /// 설정 파일의 include_tests를 끔
+    #[arg(long, overrides_with = "include_tests")]
+    no_include_tests: bool,
+
+    /// 매니페스트를 병합할 빌드 변형 (release, debug, freeDebug, ...). 기본값: release
+    #[arg(long)]
+    variant: Option<String>,
+
+    /// build/intermediates의 병합된 매니페스트가 있으면 소스 매니페스트 대신 사용
+    #[arg(long, overrides_with = "no_merged_manifests")]
+    merged_manifests: bool,
+
+    /// 설정 파일의 merged_manifests를 끔
+    #[arg(long, overrides_with = "merged_manifests")]
+    no_merged_manifests: bool,
+
+    /// 분석할 컴포넌트 타입 (activity, service, receiver, provider)
+    #[arg(long, value_delimiter = ',')]
+    component_type: Vec<String>,
+
+    /// 결과에서 제외할 컴포넌트 이름 glob (반복 가능)
+    #[arg(long)]
+    suppress_component: Vec<String>,
+
+    /// 보고하지 않을 규칙 ID (반복 가능)
+    #[arg(long)]
+    suppress_rule: Vec<String>,
+
+    /// 출력 형식 (text, json, jsonl, sarif, html). 기본값: text
+    #[arg(long, value_enum)]
+    format: Option<OutputFormat>,
+
+    /// 결과를 저장할 파일 (기본값: 표준 출력)
+    #[arg(short, long)]
+    output: Option<String>,
+
+    /// 컴포넌트 XML을 원문 대신 정규화된 형태로 표시
+    #[arg(long, overrides_with = "no_pretty_xml")]
+    pretty_xml: bool,
+
+    /// 설정 파일의 pretty_xml을 끔
+    #[arg(long, overrides_with = "pretty_xml")]
+    no_pretty_xml: bool,
+
+    /// 컴포넌트별 PoC 앱 프로젝트(Gradle)를 생성할 디렉토리 (선택)
+    #[arg(long)]
+    poc_dir: Option<String>,
+
+    /// 패키지별 Frida 훅 스크립트를 생성할 디렉토리 (선택)
+    #[arg(long)]
+    frida_dir: Option<String>,
+
+    /// Frida 스크립트 실행 로그 (런타임에 확인된 extra를 병합)
+    #[arg(long)]
+    frida_results: Option<String>,
+
+    /// drozer 명령어 스크립트를 저장할 파일 (선택)
+    #[arg(long)]
+    drozer_out: Option<String>,
+
+    /// 스캔 결과를 기준선 파일로 저장 (선택)
+    #[arg(long)]
+    save_baseline: Option<String>,
+
+    /// 기준선 파일과 비교해 늘어난 공격 표면을 보고 (새 무방비 표면이 있으면 종료 코드 3)
+    #[arg(long)]
+    baseline: Option<String>,
+
+    /// App Links 검증에 사용할 assetlinks.json 사본 (파일 또는 host별 디렉토리)
+    #[arg(long)]
+    assetlinks: Option<String>,
+
+    /// 앱 서명 인증서의 SHA-256 지문 (반복 가능)
+    #[arg(long)]
+    signing_cert_sha256: Vec<String>,
 }
 
-#[allow(dead_code)]
 fn get_permission_level_value(level: &str) -> u8 {
     match level {
         "normal" => 1,
@@ -68,20 +183,14 @@ fn get_permission_level_value(level: &str) -> u8 {
     }
 }
 
-#[allow(dead_code)]
 fn should_show_component(component: &Component, max_level: &str) -> bool {
     let max_level_value = get_permission_level_value(max_level);
     
     // 컴포넌트의 권한들 중 가장 높은 수준 확인
     let mut highest_level = 0;
     
-    for permission in &component.permissions {
-        let level = get_permission_level_value(get_permission_protection_level(permission));
-        highest_level = highest_level.max(level);
-    }
-    
-    for permission in &component.intent_filter_permissions {
-        let level = get_permission_level_value(get_permission_protection_level(permission));
+    for (permission, _) in component.required_permissions() {
+        let level = get_permission_level_value(component.protection_level(permission));
         highest_level = highest_level.max(level);
     }
     
@@ -108,29 +217,23 @@ fn get_alive_packages() -> Result<Vec<String>> {
     
     let packages: Vec<String> = stdout
         .lines()
-        .filter_map(|line| {
-            if line.starts_with("package:") {
-                Some(line[8..].to_string())
-            } else {
-                None
-            }
-        })
+        .filter_map(|line| line.strip_prefix("package:").map(str::to_string))
         .collect();
 
     Ok(packages)
 }
 
 async fn select_model(api_url: &str, api_key: Option<&str>, model_arg: Option<&str>) -> Result<String, Box<dyn std::error::Error>> {
-    println!("사용 가능한 모델을 가져오는 중...");
+    eprintln!("사용 가능한 모델을 가져오는 중...");
     let models = fetch_available_models(api_url, api_key).await?;
     
     if models.is_empty() {
         return Err("사용 가능한 모델이 없습니다.".into());
     }
     
-    println!("\n사용 가능한 모델:");
+    eprintln!("\n사용 가능한 모델:");
     for (i, model) in models.iter().enumerate() {
-        println!("{}. {}", i + 1, model);
+        eprintln!("{}. {}", i + 1, model);
     }
 
     // 모델 인자가 있는 경우
@@ -148,12 +251,12 @@ async fn select_model(api_url: &str, api_key: Option<&str>, model_arg: Option<&s
             return Ok(found_model.clone());
         }
         
-        println!("지정된 모델을 찾을 수 없습니다. 대화형 선택으로 전환합니다.");
+        eprintln!("지정된 모델을 찾을 수 없습니다. 대화형 선택으로 전환합니다.");
     }
     
     // 대화형 선택
     loop {
-        println!("\n모델 번호를 선택하세요 (1-{}): ", models.len());
+        eprintln!("\n모델 번호를 선택하세요 (1-{}): ", models.len());
         let mut input = String::new();
         std::io::stdin().read_line(&mut input)?;
         
@@ -162,16 +265,26 @@ async fn select_model(api_url: &str, api_key: Option<&str>, model_arg: Option<&s
                 return Ok(models[index - 1].clone());
             }
         }
-        println!("잘못된 선택입니다. 다시 시도하세요.");
+        eprintln!("잘못된 선택입니다. 다시 시도하세요.");
     }
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let args = Args::parse();
-    
+    let mut args = Args::parse();
+
+    // 설정 파일 (CLI 플래그가 우선)
+    let config = config::load_config(args.config.as_deref().map(Path::new), Path::new(&args.dir))?;
+    let config_path = config.map(|(config, path)| {
+        apply_config(&mut args, config);
+        path
+    });
+
     // 로깅 설정
-    setup_logging(&args.log_level)?;
+    setup_logging(args.log_level.as_deref().unwrap_or("info"))?;
+    if let Some(path) = &config_path {
+        info!("Loaded config file: {}", path.display());
+    }
     
     // LLM 설정
     let llm_config = setup_llm_config(&args).await?;
@@ -182,8 +295,108 @@ async fn main() -> Result<()> {
     // 컴포넌트 분석
     let components = analyze_components(&manifest_dir, &args).await?;
     
+    // Frida 런타임 결과 불러오기
+    let runtime_extras = match &args.frida_results {
+        Some(path) => export::import_frida_results(Path::new(path))?,
+        None => HashMap::new(),
+    };
+
     // ADB 명령어 생성 및 실행
-    generate_and_run_adb_commands(&components, &llm_config).await?;
+    let mut analyses = generate_and_run_adb_commands(&components, &llm_config, &runtime_extras).await?;
+
+    // assetlinks.json 사본으로 App Links 검증
+    if let Some(assetlinks) = &args.assetlinks {
+        let mirror = rules::app_links::AssetLinksMirror::new(PathBuf::from(assetlinks), &args.signing_cert_sha256);
+        info!("Verifying App Links against {}", mirror.path().display());
+        if args.signing_cert_sha256.is_empty() {
+            warn!("No --signing-cert-sha256 given; App Links are checked by package name only");
+        }
+        for analysis in &mut analyses {
+            analysis.findings.extend(rules::app_links::check_verification(&analysis.component, &mirror));
+        }
+    }
+
+    // extra로 받은 intent를 그대로 시작하거나 돌려주는 경로
+    for analysis in &mut analyses {
+        if let Some(source_file) = &analysis.source_file {
+            let (redirections, findings) = rules::redirection::check(&analysis.component, source_file, &components);
+            analysis.redirections = redirections;
+            analysis.findings.extend(findings);
+        }
+    }
+
+    // 여러 앱이 같은 scheme/host/path나 MIME 타입을 등록한 경우
+    let collisions = rules::collisions::check(&components);
+    for analysis in &mut analyses {
+        let component = &analysis.component;
+        analysis.findings.extend(collisions.iter()
+            .filter(|f| f.component == component.name && f.location.path == component.manifest_path)
+            .cloned());
+    }
+    // 컴포넌트에 속하지 않는 소스 코드 (PendingIntent)
+    let mut source_findings = check_source_files(&components);
+    for analysis in &mut analyses {
+        analysis.findings.retain(|finding| !args.suppress_rule.contains(&finding.rule_id));
+    }
+    source_findings.retain(|finding| !args.suppress_rule.contains(&finding.rule_id));
+
+    // 결과 출력
+    let output_options = OutputOptions {
+        format: args.format.unwrap_or(OutputFormat::Text),
+        scan_root: manifest_dir.clone(),
+        pretty_xml: args.pretty_xml,
+    };
+    match &args.output {
+        Some(path) => {
+            let mut file = std::io::BufWriter::new(
+                std::fs::File::create(path).with_context(|| format!("Failed to create {}", path))?
+            );
+            export::write_results(&analyses, &source_findings, &output_options, &mut file)?;
+            file.flush()?;
+            info!("Wrote results to {}", path);
+        }
+        None => export::write_results(&analyses, &source_findings, &output_options, &mut std::io::stdout().lock())?,
+    }
+
+    // PoC 앱 프로젝트 생성
+    if let Some(poc_dir) = &args.poc_dir {
+        write_poc_projects(&analyses, Path::new(poc_dir))?;
+    }
+
+    // drozer 스크립트 생성
+    if let Some(drozer_out) = &args.drozer_out {
+        let count = export::write_drozer_script(&analyses, Path::new(drozer_out))?;
+        info!("Wrote {} drozer commands to {}", count, drozer_out);
+    }
+
+    // Frida 훅 스크립트 생성
+    if let Some(frida_dir) = &args.frida_dir {
+        for script in export::write_frida_scripts(&components, Path::new(frida_dir))? {
+            info!("Generated Frida script: {}", script.display());
+        }
+    }
+
+    // 기준선 비교 및 저장 (같은 파일을 쓰는 경우를 위해 먼저 읽는다)
+    if args.save_baseline.is_some() || args.baseline.is_some() {
+        let report = export::json::build_report(&analyses, &source_findings);
+        let diff = match &args.baseline {
+            Some(path) => {
+                let baseline = export::baseline::load_baseline(Path::new(path))?;
+                Some(export::baseline::diff_reports(&baseline, &report))
+            }
+            None => None,
+        };
+        if let Some(path) = &args.save_baseline {
+            export::baseline::save_baseline(&report, Path::new(path))?;
+            info!("Saved baseline to {}", path);
+        }
+        if let Some(diff) = diff {
+            export::baseline::write_diff(&diff, &mut std::io::stderr().lock())?;
+            if diff.has_new_unprotected_surface() {
+                std::process::exit(export::baseline::NEW_SURFACE_EXIT_CODE);
+            }
+        }
+    }
 
     Ok(())
 }
@@ -197,7 +410,7 @@ fn setup_logging(log_level: &str) -> Result<()> {
         _ => Level::INFO,
     };
 
-    let _subscriber = FmtSubscriber::builder()
+    FmtSubscriber::builder()
         .with_max_level(level)
         .with_target(false)
         .with_thread_ids(false)
@@ -205,6 +418,7 @@ fn setup_logging(log_level: &str) -> Result<()> {
         .with_line_number(false)
         .with_thread_names(false)
         .with_ansi(true)
+        .with_writer(std::io::stderr)
         .with_timer(tracing_subscriber::fmt::time::LocalTime::rfc_3339())
         .with_level(true)
         .init();
@@ -213,7 +427,7 @@ fn setup_logging(log_level: &str) -> Result<()> {
 }
 
 async fn setup_llm_config(args: &Args) -> Result<LLMConfig> {
-    let config = match &args.llm_url {
+    let mut config = match &args.llm_url {
         None => {
             // LLM URL이 없는 경우 빈 설정 반환
             LLMConfig::new(
@@ -240,29 +454,131 @@ async fn setup_llm_config(args: &Args) -> Result<LLMConfig> {
             )
         }
     };
+    if let Some(context_lines) = args.llm_context_lines {
+        config.context_lines = context_lines;
+    }
     Ok(config)
 }
 
+/// 설정 파일 값을 CLI에서 지정하지 않은 항목에만 적용한다.
+fn apply_config(args: &mut Args, config: config::Config) {
+    let config::Config { llm, scan, suppress, output, app_links } = config;
+
+    args.llm_url = args.llm_url.take().or(llm.url);
+    args.llm_key = args.llm_key.take().or(llm.key);
+    args.llm_model = args.llm_model.take().or(llm.model);
+    args.llm_context_lines = args.llm_context_lines.or(llm.context_lines);
+
+    args.package = args.package.take().or(scan.package);
+    args.max_permission_level = args.max_permission_level.take().or(scan.max_permission_level);
+    apply_flag(&mut args.alive_only, args.no_alive_only, scan.alive_only);
+    apply_flag(&mut args.no_shared_userid, args.shared_userid, scan.no_shared_userid);
+    if args.include.is_empty() {
+        args.include = scan.include;
+    }
+    if args.exclude.is_empty() {
+        args.exclude = scan.exclude;
+    }
+    apply_flag(&mut args.include_tests, args.no_include_tests, scan.include_tests);
+    args.variant = args.variant.take().or(scan.variant);
+    apply_flag(&mut args.merged_manifests, args.no_merged_manifests, scan.merged_manifests);
+    if args.component_type.is_empty() {
+        args.component_type = scan.component_types;
+    }
+
+    if args.suppress_component.is_empty() {
+        args.suppress_component = suppress.components;
+    }
+    if args.suppress_rule.is_empty() {
+        args.suppress_rule = suppress.rules;
+    }
+
+    args.assetlinks = args.assetlinks.take().or(app_links.assetlinks);
+    if args.signing_cert_sha256.is_empty() {
+        args.signing_cert_sha256 = app_links.signing_cert_sha256;
+    }
+
+    args.format = args.format.or(output.format);
+    args.output = args.output.take().or(output.output);
+    apply_flag(&mut args.pretty_xml, args.no_pretty_xml, output.pretty_xml);
+    args.log_level = args.log_level.take().or(output.log_level);
+}
+
+/// `--flag`와 `--no-flag` 중 어느 것도 지정하지 않았을 때만 설정 파일 값을 쓴다.
+fn apply_flag(flag: &mut bool, negated: bool, config: Option<bool>) {
+    if !*flag && !negated {
+        *flag = config.unwrap_or(false);
+    }
+}
+
+fn compile_globs(patterns: &[String]) -> Result<Vec<glob::Pattern>> {
+    patterns.iter()
+        .map(|p| glob::Pattern::new(p).with_context(|| format!("Invalid glob pattern: {}", p)))
+        .collect()
+}
+
 fn setup_manifest_parser(args: &Args) -> Result<PathBuf> {
     let manifest_dir = PathBuf::from(&args.dir);
     Ok(manifest_dir)
 }
 
-async fn analyze_components(manifest_dir: &PathBuf, args: &Args) -> Result<Vec<Component>> {
+async fn analyze_components(manifest_dir: &Path, args: &Args) -> Result<Vec<Component>> {
     info!("Scanning directory for AndroidManifest.xml files: {}", manifest_dir.display());
     
     // Find all AndroidManifest.xml files
-    let manifest_files = find_manifest_files(manifest_dir.to_str().unwrap());
-    info!("Found {} AndroidManifest.xml files", manifest_files.len());
+    // Gradle 프로젝트 모델 (모듈, namespace/applicationId, 소스 세트)
+    let projects = gradle::find_projects(manifest_dir);
+    for project in &projects {
+        info!("Found Gradle project {} with {} modules", project.root.display(), project.modules.len());
+        for module in &project.modules {
+            debug!("  {} ({:?}) namespace={:?} applicationId={:?} dependencies={:?}",
+                module.path, module.kind, module.namespace, module.application_id, module.dependencies);
+        }
+    }
+
+    let discovery_options = DiscoveryOptions {
+        include: compile_globs(&args.include)?,
+        exclude: compile_globs(&args.exclude)?,
+        include_tests: args.include_tests,
+        extra_manifests: projects.iter().flat_map(|p| p.manifest_files()).collect(),
+    };
+    let suppressed = compile_globs(&args.suppress_component)?;
+    let max_permission_level = args.max_permission_level.as_deref();
+
+    let discovery = find_manifest_files(manifest_dir.to_str().unwrap(), &discovery_options);
+    info!("Found {} AndroidManifest.xml files", discovery.manifests.len());
+    if !discovery.skipped.is_empty() {
+        info!("Skipped {} AndroidManifest.xml files and directories:", discovery.skipped.len());
+        for (path, reason) in &discovery.skipped {
+            info!("  {}: {}", path.display(), reason);
+        }
+    }
+    let manifest_files = discovery.manifests;
 
     let mut all_components = Vec::new();
     
     // Parse each manifest file
     for manifest_path in manifest_files {
         info!("Parsing manifest file: {}", manifest_path.display());
-        match parse_manifest(&manifest_path, args.package.as_deref()) {
-            Ok(components) => {
+        let module = gradle::module_for_manifest(&projects, &manifest_path);
+        let namespace = module.and_then(|(_, m)| m.namespace.as_deref());
+        let application_id = module.and_then(|(_, m)| m.application_id.as_deref());
+        match parse_manifest(&manifest_path, None, namespace, application_id) {
+            Ok(mut components) => {
                 info!("Found {} components in {}", components.len(), manifest_path.display());
+                if let Some((project, module)) = module {
+                    let source_roots = project.source_roots(module);
+                    let source_set = module.source_set_for_manifest(&manifest_path);
+                    for component in &mut components {
+                        component.module = Some(module.path.clone());
+                        // Gradle 설정이 <uses-sdk>보다 우선한다
+                        component.target_sdk = module.target_sdk.or(component.target_sdk);
+                        component.source_roots = source_roots.clone();
+                        if let Some(source_set) = source_set {
+                            component.source_set = Some(source_set.to_string());
+                        }
+                    }
+                }
                 all_components.extend(components);
             }
             Err(e) => {
@@ -271,9 +587,31 @@ async fn analyze_components(manifest_dir: &PathBuf, args: &Args) -> Result<Vec<C
         }
     }
 
+    // 앱 모듈별 매니페스트 병합 (라이브러리, 빌드 타입/플레이버 오버레이)
+    if !projects.is_empty() {
+        let merge_options = MergeOptions {
+            variant: args.variant.as_deref().unwrap_or("release"),
+            use_merged_manifests: args.merged_manifests,
+        };
+        all_components = merge_project_components(all_components, &projects, &merge_options);
+    }
+    // `${placeholder}`와 `@string/` 참조 풀기
+    resolve_components(&mut all_components, &projects, args.variant.as_deref().unwrap_or("release"));
+
+    // 소스에서 registerReceiver로 등록되는 receiver
+    let dynamic_receivers = utils::receivers::find_dynamic_receivers(&all_components);
+    if !dynamic_receivers.is_empty() {
+        info!("Found {} dynamically registered receivers", dynamic_receivers.len());
+    }
+    all_components.extend(dynamic_receivers);
+
     // Filter components based on various criteria
     let components: Vec<Component> = all_components.into_iter()
         .filter(|component| {
+            if args.package.as_ref().is_some_and(|package| component.package != *package) {
+                return false;
+            }
+
             // Filter by package if alive_only is set
             if args.alive_only {
                 let alive_packages = get_alive_packages().unwrap_or_default();
@@ -287,6 +625,22 @@ async fn analyze_components(manifest_dir: &PathBuf, args: &Args) -> Result<Vec<C
                 return false;
             }
 
+            // 컴포넌트 타입 필터
+            if !args.component_type.is_empty() && !args.component_type.contains(&component.component_type) {
+                return false;
+            }
+
+            // 권한 보호 수준 필터
+            if max_permission_level.is_some_and(|level| !should_show_component(component, level)) {
+                return false;
+            }
+
+            // 제외 목록
+            let class_name = export::qualified_class_name(component);
+            if suppressed.iter().any(|p| p.matches(&component.name) || p.matches(&class_name)) {
+                return false;
+            }
+
             true
         })
         .collect();
@@ -298,27 +652,61 @@ async fn analyze_components(manifest_dir: &PathBuf, args: &Args) -> Result<Vec<C
 async fn generate_and_run_adb_commands(
     components: &[Component],
     llm_config: &LLMConfig,
-) -> Result<()> {
+    runtime_extras: &HashMap<String, Vec<IntentParameter>>,
+) -> Result<Vec<ComponentAnalysis>> {
     let adb = Arc::new(Mutex::new(ADBCommand::new()?));
+    let mut analyses = Vec::new();
     
     for component in components {
-        match generate_adb_command(component, llm_config, &adb).await {
-            Ok(_) => info!("Successfully generated ADB command for {}", component.name),
+        let runtime = runtime_extras
+            .get(&export::qualified_class_name(component))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        match generate_adb_command(component, llm_config, runtime, &adb).await {
+            Ok(analysis) => {
+                info!("Successfully generated ADB command for {}", component.name);
+                analyses.push(analysis);
+            }
             Err(e) => error!("Failed to generate ADB command for {}: {}", component.name, e),
         }
     }
 
+    Ok(analyses)
+}
+
+/// 컴포넌트의 소스 디렉토리에 있는 모든 Java 파일을 검사한다.
+fn check_source_files(components: &[Component]) -> Vec<Finding> {
+    let mut cache = SourceFileCache::for_components(components);
+    if let Err(e) = cache.scan_roots() {
+        warn!("Failed to scan source files: {}", e);
+        return Vec::new();
+    }
+    let files = cache.java_files();
+    debug!("Checking {} Java files for PendingIntents", files.len());
+    rules::pending_intent::check_sources(&files)
+}
+
+fn write_poc_projects(analyses: &[ComponentAnalysis], output_dir: &Path) -> Result<()> {
+    for analysis in analyses {
+        match export::write_poc_project(analysis, output_dir) {
+            Ok(project_dir) => info!("Generated PoC project for {}: {}", analysis.component.name, project_dir.display()),
+            Err(e) => error!("Failed to generate PoC project for {}: {}", analysis.component.name, e),
+        }
+    }
     Ok(())
 }
 
 async fn generate_adb_command(
     component: &Component,
     llm_config: &LLMConfig,
+    runtime_extras: &[IntentParameter],
     adb: &Arc<Mutex<ADBCommand>>,
-) -> Result<()> {
+) -> Result<ComponentAnalysis> {
     let mut adb_cmd = adb.lock().await;
     adb_cmd.set_component(component);
     info!("Component: {}", component.name);
+    let mut extras = Vec::new();
+    let mut params_provenance = Provenance::Manifest;
 
     // LLM URL이 지정되지 않은 경우 기본 파라미터만 사용
     if llm_config.api_url.is_empty() {
@@ -333,6 +721,7 @@ async fn generate_adb_command(
                         for arg in adb_args {
                             adb_cmd.add_extra_arg(&arg);
                         }
+                        extras = parameters;
                     }
                     Err(e) => {
                         warn!("Failed to parse intent parameters: {}. Using basic parameters.", e);
@@ -362,6 +751,7 @@ async fn generate_adb_command(
                     for arg in adb_args {
                         adb_cmd.add_extra_arg(&arg);
                     }
+                    extras = parameters;
                 } else {
                     // If parsing fails, fall back to LLM analysis
                     match llm::analyzer::analyze_intent(component, &source_file.to_string_lossy(), llm_config).await {
@@ -375,6 +765,7 @@ async fn generate_adb_command(
                                     component.package
                                 ))?;
                             adb_cmd.set_intent_params(&analysis.intent_params);
+                            params_provenance = Provenance::Llm;
                         }
                         Err(e) => {
                             warn!("Failed to analyze intent with LLM: {}. Using basic parameters.", e);
@@ -396,31 +787,148 @@ async fn generate_adb_command(
             }
         }
     }
+
+    // Frida로 런타임에 확인된 extra 중 정적 분석에서 놓친 것만 추가
+    let runtime_extras = export::missing_runtime_extras(&extras, runtime_extras);
+    if !runtime_extras.is_empty() {
+        info!("Merged {} runtime extras from Frida results", runtime_extras.len());
+        for arg in intent_parameters_to_adb_args(&runtime_extras) {
+            adb_cmd.add_extra_arg(&arg);
+        }
+    }
     
+    // provider는 authorities와 UriMatcher 경로로 URI를 만든다
+    let source_file = find_source_file(component, "").ok();
+    let provider_source = match &source_file {
+        Some(source_file) if component.component_type == "provider" => {
+            match analyze_provider_source(source_file) {
+                Ok(provider_source) => Some(provider_source),
+                Err(e) => {
+                    warn!("Failed to analyze provider source {}: {}", source_file.display(), e);
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+    let content_uris = utils::provider::content_uris(component, provider_source.as_ref());
+    if let Some(uri) = content_uris.first() {
+        adb_cmd.set_content_uri(uri);
+    }
+
     let command = adb_cmd.build_command()
         .context("Failed to build ADB command")?;
-    
-    // ADB 명령어를 특별한 형식으로 출력
-    println!("\n\x1b[1;36mGenerated ADB command:\x1b[0m\n\x1b[1;33m{}\x1b[0m", command);
-    
-    // 매니페스트 정보 출력
-    println!("\x1b[1;34mManifest: {}:{}\x1b[0m", 
-        component.manifest_path.display(),
-        component.manifest_line
-    );
-    if let Some(xml) = &component.xml_element {
-        println!("\x1b[1;35mComponent XML:\x1b[0m\n{}", xml);
+
+    // intent 값이 WebView로 가는 경로마다 테스트 값을 넣은 명령어
+    let mut findings = rules::check_component(component);
+    let mut command_variants = Vec::new();
+    if let Some(source_file) = &source_file {
+        let (webview_flows, webview_findings) = rules::webview::check(component, source_file);
+        findings.extend(webview_findings);
+        command_variants = webview_command_variants(&adb_cmd, component, &webview_flows)?;
+    }
+    if let (Some(source_file), Some(provider_source)) = (&source_file, &provider_source) {
+        let (traversals, traversal_findings) = rules::provider::check(component, source_file, provider_source);
+        findings.extend(traversal_findings);
+        command_variants.extend(provider_command_variants(component, &content_uris, provider_source, &traversals));
+    }
+
+    // 진입점에서 호출자 권한을 직접 확인하면 권한 관련 발견 사항을 낮춘다
+    let permission_guards = match &source_file {
+        Some(source_file) => utils::guards::find_guards(component, source_file).unwrap_or_else(|e| {
+            warn!("Failed to find permission checks in {}: {}", source_file.display(), e);
+            Vec::new()
+        }),
+        None => Vec::new(),
+    };
+    rules::manifest::apply_guards(&mut findings, &permission_guards);
+
+    Ok(ComponentAnalysis {
+        component: component.clone(),
+        extras,
+        runtime_extras,
+        intent_params: adb_cmd.intent_params().to_vec(),
+        params_provenance,
+        command,
+        source_file,
+        findings,
+        redirections: Vec::new(),
+        command_variants,
+        content_uris,
+        permission_guards,
+    })
+}
+
+/// UriMatcher의 나머지 경로, openFile, 경로 조작 시험용 명령어
+fn provider_command_variants(
+    component: &Component,
+    content_uris: &[String],
+    provider_source: &ProviderSource,
+    traversals: &[TaintFlow],
+) -> Vec<CommandVariant> {
+    let mut variants: Vec<CommandVariant> = content_uris.iter()
+        .skip(1)
+        .map(|uri| CommandVariant {
+            command: content_command("query", uri),
+            purpose: "UriMatcher path".to_string(),
+        })
+        .collect();
+    let Some(first_uri) = content_uris.first() else {
+        return variants;
+    };
+    if let Some(method) = provider_source.file_methods.first() {
+        variants.push(CommandVariant {
+            command: content_command("read", first_uri),
+            purpose: format!("{}() at line {}", method.name, method.start_line),
+        });
     }
-    // Display source file information if available
-    if let Ok(source_file) = find_source_file(component, "") {
-        println!("\x1b[1;32mSource file: {}\x1b[0m", source_file.display());
+    for traversal in traversals {
+        let command = content_command("read", &rules::provider::traversal_uri(first_uri, &component.package));
+        if variants.iter().any(|v| v.command == command) {
+            continue;
+        }
+        variants.push(CommandVariant {
+            command,
+            purpose: format!("AINT011: path traversal at line {}", traversal.sink.line),
+        });
     }
+    variants
+}
 
-    // sharedUserId가 있는 경우 표시
-    if let Some(shared_user_id) = &component.shared_user_id {
-        println!("\x1b[1;35mNote: This component has sharedUserId: {}\x1b[0m", shared_user_id);
+fn webview_command_variants(
+    adb_cmd: &ADBCommand,
+    component: &Component,
+    flows: &[WebViewFlow],
+) -> Result<Vec<CommandVariant>> {
+    let mut variants: Vec<CommandVariant> = Vec::new();
+    for webview_flow in flows {
+        let value = webview_flow.test_value();
+        let command = match webview_flow.slot() {
+            Some(PayloadSlot::Extra(name)) => adb_cmd.build_with_string_extra(&name, value)?,
+            Some(PayloadSlot::Data) => adb_cmd.build_with_data(value)?,
+            Some(PayloadSlot::DataQuery(name)) => {
+                // 컴포넌트가 받는 URI에 쿼리 파라미터를 붙인다
+                let base = adb_cmd.intent_params().iter()
+                    .find(|p| p.flag == "-d" && !p.value.is_empty())
+                    .map(|p| p.value.clone())
+                    .or_else(|| manifest::uri::example_uris(component).into_iter().next())
+                    .unwrap_or_else(|| "https://aintent.invalid/".to_string());
+                let separator = if base.contains('?') { '&' } else { '?' };
+                adb_cmd.build_with_data(&format!("{}{}{}={}", base, separator, name, percent_encode(value)))?
+            }
+            None => continue,
+        };
+        if variants.iter().any(|v| v.command == command) {
+            continue;
+        }
+        variants.push(CommandVariant {
+            command,
+            purpose: format!(
+                "AINT009: WebView.{}() at line {}",
+                webview_flow.flow.sink_method,
+                webview_flow.flow.sink.line
+            ),
+        });
     }
-    
-    println!();
-    Ok(())
+    Ok(variants)
 }
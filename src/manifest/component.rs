@@ -1,31 +1,490 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
+use serde::{Deserialize, Serialize};
 use tracing::info;
+use crate::permissions::get_permission_protection_level;
 use std::path::PathBuf;
 
-#[derive(Debug, Clone)]
+/// <intent-filter> 하나에 선언된 action/category/data
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IntentFilter {
+    pub actions: Vec<String>,
+    pub categories: Vec<String>,
+    pub data: Vec<DataElement>,
+    pub line: usize,    // 1부터 시작
+    pub column: usize,  // 1부터 시작
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tools_node: Option<String>,  // tools:node (merge, remove, ...)
+    #[serde(default)]
+    pub auto_verify: bool,           // android:autoVerify="true" (App Links)
+}
+
+impl IntentFilter {
+    /// 위치를 제외한 선언 내용이 같은지
+    pub fn same_content(&self, other: &IntentFilter) -> bool {
+        self.actions == other.actions
+            && self.categories == other.categories
+            && self.auto_verify == other.auto_verify
+            && self.data.len() == other.data.len()
+            && self.data.iter().zip(&other.data).all(|(a, b)| a.same_attributes(b))
+    }
+}
+
+/// <data> 요소 하나의 속성
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataElement {
+    pub scheme: Option<String>,
+    pub host: Option<String>,
+    pub path: Option<String>,
+    // port, path*, ssp*, fragment*: 선언한 경우에만 출력
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path_prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path_pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path_advanced_pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path_suffix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssp: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssp_prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssp_pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssp_advanced_pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssp_suffix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fragment: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fragment_prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fragment_pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fragment_advanced_pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fragment_suffix: Option<String>,
+    pub mime_type: Option<String>,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl DataElement {
+    /// 매니페스트 속성 이름(`pathPrefix`, `sspPattern`, ...)으로 값을 설정한다. 모르는 속성이면 false
+    pub fn set_attribute(&mut self, name: &str, value: String) -> bool {
+        let field = match name {
+            "scheme" => &mut self.scheme,
+            "host" => &mut self.host,
+            "port" => &mut self.port,
+            "path" => &mut self.path,
+            "pathPrefix" => &mut self.path_prefix,
+            "pathPattern" => &mut self.path_pattern,
+            "pathAdvancedPattern" => &mut self.path_advanced_pattern,
+            "pathSuffix" => &mut self.path_suffix,
+            "ssp" => &mut self.ssp,
+            "sspPrefix" => &mut self.ssp_prefix,
+            "sspPattern" => &mut self.ssp_pattern,
+            "sspAdvancedPattern" => &mut self.ssp_advanced_pattern,
+            "sspSuffix" => &mut self.ssp_suffix,
+            "fragment" => &mut self.fragment,
+            "fragmentPrefix" => &mut self.fragment_prefix,
+            "fragmentPattern" => &mut self.fragment_pattern,
+            "fragmentAdvancedPattern" => &mut self.fragment_advanced_pattern,
+            "fragmentSuffix" => &mut self.fragment_suffix,
+            "mimeType" => &mut self.mime_type,
+            _ => return false,
+        };
+        *field = Some(value);
+        true
+    }
+
+    /// 선언된 모든 속성 값
+    pub fn values(&self) -> impl Iterator<Item = &String> {
+        [
+            &self.scheme,
+            &self.host,
+            &self.port,
+            &self.path,
+            &self.path_prefix,
+            &self.path_pattern,
+            &self.path_advanced_pattern,
+            &self.path_suffix,
+            &self.ssp,
+            &self.ssp_prefix,
+            &self.ssp_pattern,
+            &self.ssp_advanced_pattern,
+            &self.ssp_suffix,
+            &self.fragment,
+            &self.fragment_prefix,
+            &self.fragment_pattern,
+            &self.fragment_advanced_pattern,
+            &self.fragment_suffix,
+            &self.mime_type,
+        ].into_iter().flatten()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut String> {
+        [
+            &mut self.scheme,
+            &mut self.host,
+            &mut self.port,
+            &mut self.path,
+            &mut self.path_prefix,
+            &mut self.path_pattern,
+            &mut self.path_advanced_pattern,
+            &mut self.path_suffix,
+            &mut self.ssp,
+            &mut self.ssp_prefix,
+            &mut self.ssp_pattern,
+            &mut self.ssp_advanced_pattern,
+            &mut self.ssp_suffix,
+            &mut self.fragment,
+            &mut self.fragment_prefix,
+            &mut self.fragment_pattern,
+            &mut self.fragment_advanced_pattern,
+            &mut self.fragment_suffix,
+            &mut self.mime_type,
+        ].into_iter().flatten()
+    }
+
+    /// 위치를 제외한 속성이 모두 같은지
+    pub fn same_attributes(&self, other: &DataElement) -> bool {
+        let without_position = |data: &DataElement| DataElement { line: 0, column: 0, ..data.clone() };
+        without_position(self) == without_position(other)
+    }
+}
+
+/// FileProvider가 공유할 경로 XML을 가리키는 `<meta-data>` 이름
+pub const FILE_PROVIDER_PATHS: &str = "android.support.FILE_PROVIDER_PATHS";
+
+/// 컴포넌트 안의 `<meta-data>` 하나
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetaData {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// android:resource (`@xml/file_paths` 등)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource: Option<String>,
+}
+
+/// FileProvider 경로 XML(`<paths>`)의 항목 하나
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileProviderPath {
+    /// 요소 이름 (root-path, files-path, cache-path, external-path, ...)
+    pub kind: String,
+    /// URI의 첫 경로 세그먼트
+    pub name: String,
+    /// 기준 디렉토리 아래의 하위 경로
+    pub path: String,
+    pub line: usize,
+}
+
+impl FileProviderPath {
+    /// 기기에서의 기준 디렉토리. 모르는 요소면 None
+    pub fn base_dir(&self, package: &str) -> Option<String> {
+        Some(match self.kind.as_str() {
+            "root-path" => String::new(),
+            "files-path" => format!("/data/data/{}/files", package),
+            "cache-path" => format!("/data/data/{}/cache", package),
+            "external-path" => "/sdcard".to_string(),
+            "external-files-path" => format!("/sdcard/Android/data/{}/files", package),
+            "external-cache-path" => format!("/sdcard/Android/data/{}/cache", package),
+            "external-media-path" => format!("/sdcard/Android/media/{}", package),
+            _ => return None,
+        })
+    }
+
+    /// 공유되는 디렉토리 (기준 디렉토리 + path)
+    pub fn shared_dir(&self, package: &str) -> Option<String> {
+        let base = self.base_dir(package)?;
+        let path = self.path.trim_matches('/');
+        Some(match path {
+            "" | "." => if base.is_empty() { "/".to_string() } else { base },
+            _ => format!("{}/{}", base, path),
+        })
+    }
+}
+
+/// `<grant-uri-permission>`이나 `<path-permission>`의 경로 조건
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProviderPath {
+    /// 속성 이름 (path, pathPrefix, pathPattern, pathAdvancedPattern, pathSuffix)
+    pub attribute: String,
+    pub value: String,
+}
+
+impl ProviderPath {
+    /// 경로 속성이면 값을 저장한다.
+    fn from_attribute(name: &str, value: &str) -> Option<Self> {
+        matches!(name, "path" | "pathPrefix" | "pathPattern" | "pathAdvancedPattern" | "pathSuffix")
+            .then(|| Self { attribute: name.to_string(), value: value.to_string() })
+    }
+}
+
+/// `<path-permission>` 하나
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PathPermission {
+    pub path: ProviderPath,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permission: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_permission: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_permission: Option<String>,
+}
+
+/// `<provider>`에만 있는 속성과 하위 요소
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProviderInfo {
+    /// android:authorities (`;`로 구분된 값)
+    pub authorities: Vec<String>,
+    #[serde(default)]
+    pub grant_uri_permissions: bool,
+    #[serde(default)]
+    pub multiprocess: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_permission: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_permission: Option<String>,
+    /// `<grant-uri-permission>`: grantUriPermissions가 false일 때 권한을 줄 수 있는 경로
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub grant_uri_paths: Vec<ProviderPath>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path_permissions: Vec<PathPermission>,
+    /// FILE_PROVIDER_PATHS meta-data가 가리키는 XML 파일
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_paths_resource: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_paths: Vec<FileProviderPath>,
+}
+
+impl ProviderInfo {
+    /// `<provider>` 요소의 속성 하나를 저장한다. 모르는 속성이면 false
+    pub fn set_attribute(&mut self, name: &str, value: &str) -> bool {
+        match name {
+            "authorities" => self.authorities = value.split(';')
+                .map(str::trim)
+                .filter(|a| !a.is_empty())
+                .map(str::to_string)
+                .collect(),
+            "grantUriPermissions" => self.grant_uri_permissions = value == "true",
+            "multiprocess" => self.multiprocess = value == "true",
+            "readPermission" => self.read_permission = Some(value.to_string()),
+            "writePermission" => self.write_permission = Some(value.to_string()),
+            _ => return false,
+        }
+        true
+    }
+
+    /// 플레이스홀더가 들어갈 수 있는 값 (authorities와 권한 이름)
+    pub fn values(&self) -> impl Iterator<Item = &String> {
+        self.authorities.iter()
+            .chain(&self.read_permission)
+            .chain(&self.write_permission)
+            .chain(self.path_permissions.iter().flat_map(|p| {
+                p.permission.iter().chain(&p.read_permission).chain(&p.write_permission)
+            }))
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut String> {
+        self.authorities.iter_mut()
+            .chain(&mut self.read_permission)
+            .chain(&mut self.write_permission)
+            .chain(self.path_permissions.iter_mut().flat_map(|p| {
+                p.permission.iter_mut().chain(&mut p.read_permission).chain(&mut p.write_permission)
+            }))
+    }
+
+    /// `<grant-uri-permission>` 요소
+    pub fn add_grant_uri_permission<'a>(&mut self, attributes: impl Iterator<Item = (&'a str, &'a str)>) {
+        self.grant_uri_paths.extend(attributes.filter_map(|(name, value)| ProviderPath::from_attribute(name, value)));
+    }
+
+    /// `<path-permission>` 요소
+    pub fn add_path_permission<'a>(&mut self, attributes: impl Iterator<Item = (&'a str, &'a str)>) {
+        let mut path_permission = PathPermission::default();
+        let mut has_path = false;
+        for (name, value) in attributes {
+            match name {
+                "permission" => path_permission.permission = Some(value.to_string()),
+                "readPermission" => path_permission.read_permission = Some(value.to_string()),
+                "writePermission" => path_permission.write_permission = Some(value.to_string()),
+                _ => {
+                    if let Some(path) = ProviderPath::from_attribute(name, value) {
+                        path_permission.path = path;
+                        has_path = true;
+                    }
+                }
+            }
+        }
+        if has_path {
+            self.path_permissions.push(path_permission);
+        }
+    }
+
+    /// 우선순위가 낮은 선언에만 있는 값을 가져온다.
+    pub fn merge_from(&mut self, lower: &ProviderInfo) {
+        for authority in &lower.authorities {
+            if !self.authorities.contains(authority) {
+                self.authorities.push(authority.clone());
+            }
+        }
+        self.read_permission = self.read_permission.take().or_else(|| lower.read_permission.clone());
+        self.write_permission = self.write_permission.take().or_else(|| lower.write_permission.clone());
+        for path in &lower.grant_uri_paths {
+            if !self.grant_uri_paths.contains(path) {
+                self.grant_uri_paths.push(path.clone());
+            }
+        }
+        for path_permission in &lower.path_permissions {
+            if !self.path_permissions.contains(path_permission) {
+                self.path_permissions.push(path_permission.clone());
+            }
+        }
+    }
+}
+
+/// `<activity>`의 태스크 관련 속성과 `<application>`의 기본값
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ActivityInfo {
+    /// standard, singleTop, singleTask, singleInstance, singleInstancePerTask
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub launch_mode: Option<String>,
+    /// android:taskAffinity. 빈 문자열이면 어떤 태스크와도 affinity가 없다
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_affinity: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_task_reparenting: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude_from_recents: Option<bool>,
+    /// none, intoExisting, always, never
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_launch_mode: Option<String>,
+    /// `<application>`의 taskAffinity
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application_task_affinity: Option<String>,
+    /// `<application>`의 allowTaskReparenting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application_allow_task_reparenting: Option<bool>,
+}
+
+impl ActivityInfo {
+    /// `<activity>` 요소의 속성 하나를 저장한다. 모르는 속성이면 false
+    pub fn set_attribute(&mut self, name: &str, value: &str) -> bool {
+        match name {
+            "launchMode" => self.launch_mode = Some(value.to_string()),
+            "taskAffinity" => self.task_affinity = Some(value.to_string()),
+            "allowTaskReparenting" => self.allow_task_reparenting = Some(value == "true"),
+            "excludeFromRecents" => self.exclude_from_recents = Some(value == "true"),
+            "documentLaunchMode" => self.document_launch_mode = Some(value.to_string()),
+            _ => return false,
+        }
+        true
+    }
+
+    /// 실제 affinity: activity, application, 패키지 이름 순
+    pub fn effective_task_affinity<'a>(&'a self, package: &'a str) -> &'a str {
+        self.task_affinity.as_deref()
+            .or(self.application_task_affinity.as_deref())
+            .unwrap_or(package)
+    }
+
+    pub fn allows_task_reparenting(&self) -> bool {
+        self.allow_task_reparenting.or(self.application_allow_task_reparenting).unwrap_or(false)
+    }
+
+    /// 플레이스홀더가 들어갈 수 있는 값 (affinity)
+    pub fn values(&self) -> impl Iterator<Item = &String> {
+        self.task_affinity.iter().chain(&self.application_task_affinity)
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut String> {
+        self.task_affinity.iter_mut().chain(&mut self.application_task_affinity)
+    }
+
+    /// 우선순위가 낮은 선언의 값은 높은 쪽에 없을 때만 쓴다.
+    pub fn merge_from(&mut self, lower: &ActivityInfo) {
+        self.launch_mode = self.launch_mode.take().or_else(|| lower.launch_mode.clone());
+        self.task_affinity = self.task_affinity.take().or_else(|| lower.task_affinity.clone());
+        self.allow_task_reparenting = self.allow_task_reparenting.or(lower.allow_task_reparenting);
+        self.exclude_from_recents = self.exclude_from_recents.or(lower.exclude_from_recents);
+        self.document_launch_mode = self.document_launch_mode.take().or_else(|| lower.document_launch_mode.clone());
+        self.application_task_affinity = self.application_task_affinity.take()
+            .or_else(|| lower.application_task_affinity.clone());
+        self.application_allow_task_reparenting = self.application_allow_task_reparenting
+            .or(lower.application_allow_task_reparenting);
+    }
+}
+
+/// 소스 코드에서 `registerReceiver`로 등록된 receiver의 등록 위치
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DynamicRegistration {
+    /// 등록한 클래스
+    pub registered_by: String,
+    /// registerReceiver 호출 코드
+    pub code: String,
+    /// RECEIVER_EXPORTED 또는 RECEIVER_NOT_EXPORTED. 플래그가 없으면 None
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export_flag: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Component {
     pub name: String,           // 전체 이름 (package.class_name)
     pub class_name: String,     // 클래스 이름만
     pub package: String,        // 패키지 이름
     pub component_type: String, // activity, service, receiver, provider
     pub exported: bool,
-    pub actions: HashSet<String>,
-    pub categories: HashSet<String>,
-    pub data_schemes: HashSet<String>,
-    pub data_hosts: HashSet<String>,
-    pub data_paths: HashSet<String>,
-    pub data_mime_types: HashSet<String>,
+    pub actions: BTreeSet<String>,
+    pub categories: BTreeSet<String>,
+    pub data_schemes: BTreeSet<String>,
+    pub data_hosts: BTreeSet<String>,
+    pub data_paths: BTreeSet<String>,
+    pub data_mime_types: BTreeSet<String>,
     pub permissions: Vec<String>,
     pub intent_filter_permissions: Vec<String>,
     pub manifest_dir: PathBuf,
     pub shared_user_id: Option<String>,
     pub manifest_path: PathBuf,  // AndroidManifest.xml 파일 경로
-    pub manifest_line: usize,    // 컴포넌트 선언의 줄 번호
-    pub xml_element: Option<String>,
+    pub manifest_line: usize,    // 컴포넌트 선언의 줄 번호 (1부터 시작)
+    pub manifest_column: usize,  // 컴포넌트 선언의 열 번호 (1부터 시작)
+    #[serde(default)]
+    pub source_set: Option<String>,  // Gradle 소스 세트 (main, debug, ...)
+    #[serde(default)]
+    pub module: Option<String>,      // Gradle 모듈 경로 (:app, ...)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub source_roots: Vec<PathBuf>,  // 소스 파일을 찾을 디렉토리 (비어 있으면 manifest_dir)
+    pub xml_element: Option<String>,      // 매니페스트 원문 그대로의 컴포넌트 XML
+    pub xml_normalized: Option<String>,   // 들여쓰기를 정리한 XML
+    pub intent_filters: Vec<IntentFilter>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tools_node: Option<String>,       // tools:node (merge, replace, remove, ...)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tools_replace: Vec<String>,       // tools:replace 속성 이름 (android: 접두사 제외)
+    #[serde(skip)]
+    pub declared_attributes: BTreeSet<String>,  // 요소에 직접 선언된 android: 속성 이름
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub merged_from: Vec<PathBuf>,        // 병합된 우선순위가 낮은 선언의 매니페스트
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<ProviderInfo>,   // provider의 authorities, 권한, grant-uri-permission
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub meta_data: Vec<MetaData>,         // 컴포넌트 안의 <meta-data>
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dynamic: Option<DynamicRegistration>,  // 소스에서 등록된 receiver (manifest_path/line은 호출 위치)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity: Option<ActivityInfo>,   // activity의 launchMode, taskAffinity 등
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_sdk: Option<u32>,          // 앱의 targetSdkVersion (Gradle 설정 또는 <uses-sdk>)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub permission_levels: BTreeMap<String, String>,  // 앱의 매니페스트에 선언된 <permission>의 보호 수준
 }
 
 impl Component {
+    #[allow(dead_code, clippy::too_many_arguments)]
     pub fn new(
         name: String,
         class_name: String,
@@ -47,11 +506,11 @@ impl Component {
             package,
             component_type,
             exported,
-            actions: HashSet::new(),
-            categories: HashSet::new(),
-            data_schemes: HashSet::new(),
-            data_hosts: HashSet::new(),
-            data_paths: HashSet::new(),
+            actions: BTreeSet::new(),
+            categories: BTreeSet::new(),
+            data_schemes: BTreeSet::new(),
+            data_hosts: BTreeSet::new(),
+            data_paths: BTreeSet::new(),
             data_mime_types: data_mime_types.into_iter().collect(),
             permissions,
             intent_filter_permissions,
@@ -59,10 +518,27 @@ impl Component {
             shared_user_id,
             manifest_path,
             manifest_line,
+            manifest_column: 0,
+            source_set: None,
+            module: None,
+            source_roots: Vec::new(),
             xml_element,
+            xml_normalized: None,
+            intent_filters: Vec::new(),
+            tools_node: None,
+            tools_replace: Vec::new(),
+            declared_attributes: BTreeSet::new(),
+            merged_from: Vec::new(),
+            provider: None,
+            meta_data: Vec::new(),
+            dynamic: None,
+            activity: None,
+            target_sdk: None,
+            permission_levels: BTreeMap::new(),
         }
     }
 
+    #[allow(dead_code)]
     pub fn from_path(path: &Path) -> Option<Self> {
         // Extract package and component name from path
         let path_str = path.to_string_lossy();
@@ -109,23 +585,99 @@ impl Component {
             package,
             component_type: component_type.to_string(),
             exported: false,
-            actions: HashSet::new(),
-            categories: HashSet::new(),
-            data_schemes: HashSet::new(),
-            data_hosts: HashSet::new(),
-            data_paths: HashSet::new(),
-            data_mime_types: HashSet::new(),
+            actions: BTreeSet::new(),
+            categories: BTreeSet::new(),
+            data_schemes: BTreeSet::new(),
+            data_hosts: BTreeSet::new(),
+            data_paths: BTreeSet::new(),
+            data_mime_types: BTreeSet::new(),
             permissions: Vec::new(),
             intent_filter_permissions: Vec::new(),
             manifest_dir: PathBuf::new(),
             shared_user_id: None,
             manifest_path: PathBuf::new(),
             manifest_line: 0,
+            manifest_column: 0,
+            source_set: None,
+            module: None,
+            source_roots: Vec::new(),
             xml_element: None,
+            xml_normalized: None,
+            intent_filters: Vec::new(),
+            tools_node: None,
+            tools_replace: Vec::new(),
+            declared_attributes: BTreeSet::new(),
+            merged_from: Vec::new(),
+            provider: None,
+            meta_data: Vec::new(),
+            dynamic: None,
+            activity: None,
+            target_sdk: None,
+            permission_levels: BTreeMap::new(),
         })
     }
 
+    #[allow(dead_code)]
     pub fn set_shared_user_id(&mut self, shared_user_id: String) {
         self.shared_user_id = Some(shared_user_id);
     }
+
+    /// intent_filters를 바꾼 뒤 actions/categories/data_* 합집합을 다시 계산한다.
+    pub fn refresh_intent_data(&mut self) {
+        self.actions = self.intent_filters.iter().flat_map(|f| f.actions.iter().cloned()).collect();
+        self.categories = self.intent_filters.iter().flat_map(|f| f.categories.iter().cloned()).collect();
+        let data: Vec<&DataElement> = self.intent_filters.iter().flat_map(|f| &f.data).collect();
+        self.data_schemes = data.iter().filter_map(|d| d.scheme.clone()).collect();
+        self.data_hosts = data.iter().filter_map(|d| d.host.clone()).collect();
+        self.data_paths = data.iter().filter_map(|d| d.path.clone()).collect();
+        self.data_mime_types = data.iter().filter_map(|d| d.mime_type.clone()).collect();
+    }
+
+    /// 실제로 다른 앱에서 호출할 수 있는지.
+    ///
+    /// `android:exported`가 없으면 targetSdk 31 미만에서 intent-filter가 있는 컴포넌트는 exported이고,
+    /// provider는 targetSdk 16 이하에서 exported다. targetSdk를 모르면 provider를 포함해 모두 오래된 앱으로 본다
+    /// (31 이상에서는 intent-filter가 있는데 속성이 없으면 설치되지 않는다).
+    pub fn is_effectively_exported(&self) -> bool {
+        if self.dynamic.is_some() || self.declared_attributes.contains("exported") {
+            return self.exported;
+        }
+        match self.component_type.as_str() {
+            "provider" => self.target_sdk.is_none_or(|sdk| sdk <= 16),
+            _ => !self.intent_filters.is_empty() && self.target_sdk.is_none_or(|sdk| sdk < 31),
+        }
+    }
+
+    /// 권한의 보호 수준. 앱이 선언한 `<permission>`을 먼저 보고, 없으면 플랫폼 권한 표를 본다
+    pub fn protection_level(&self, permission: &str) -> &str {
+        self.permission_levels.get(permission)
+            .map(String::as_str)
+            .unwrap_or_else(|| get_permission_protection_level(permission))
+    }
+
+    /// 컴포넌트에 걸린 모든 권한과 그 선언 위치
+    /// (`component`, `intent-filter`, `provider-read`, `provider-write`, `path-permission`)
+    pub fn required_permissions(&self) -> Vec<(&str, &'static str)> {
+        let mut permissions: Vec<(&str, &'static str)> = self.permissions.iter()
+            .map(|p| (p.as_str(), "component"))
+            .chain(self.intent_filter_permissions.iter().map(|p| (p.as_str(), "intent-filter")))
+            .collect();
+        if let Some(provider) = &self.provider {
+            permissions.extend(provider.read_permission.iter().map(|p| (p.as_str(), "provider-read")));
+            permissions.extend(provider.write_permission.iter().map(|p| (p.as_str(), "provider-write")));
+            permissions.extend(provider.path_permissions.iter()
+                .flat_map(|p| p.permission.iter().chain(&p.read_permission).chain(&p.write_permission))
+                .map(|p| (p.as_str(), "path-permission")));
+        }
+        let mut seen = BTreeSet::new();
+        permissions.retain(|permission| seen.insert(*permission));
+        permissions
+    }
+
+    /// FileProvider 경로 XML 참조 (`@xml/file_paths`)
+    pub fn file_provider_paths(&self) -> Option<&str> {
+        self.meta_data.iter()
+            .find(|m| m.name == FILE_PROVIDER_PATHS)
+            .and_then(|m| m.resource.as_deref())
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,146 @@
+use std::fmt;
+use std::path::{Component as PathComponent, Path, PathBuf};
+use glob::Pattern;
+
+/// 매니페스트 탐색 설정
+#[derive(Debug, Default)]
+pub struct DiscoveryOptions {
+    /// 스캔 디렉토리 기준 상대 경로에 적용할 glob. 비어 있으면 모두 포함
+    pub include: Vec<Pattern>,
+    pub exclude: Vec<Pattern>,
+    /// test/androidTest 소스 세트의 매니페스트도 분석
+    pub include_tests: bool,
+    /// 디렉토리 탐색 외에 추가로 고려할 매니페스트 (Gradle sourceSets의 manifest.srcFile 등)
+    pub extra_manifests: Vec<PathBuf>,
+}
+
+/// 매니페스트를 분석 대상에서 뺀 이유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    /// test, androidTest 등 테스트 소스 세트
+    TestSourceSet(String),
+    /// build/intermediates 등 빌드 결과물
+    BuildOutput,
+    /// include glob과 일치하지 않음
+    NotIncluded,
+    /// exclude glob과 일치
+    Excluded(String),
+    /// `.`으로 시작하는 디렉토리 (.idea, .gradle, ...). 안으로 들어가지 않는다
+    Hidden,
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkipReason::TestSourceSet(name) => write!(f, "test source set '{}' (use --include-tests)", name),
+            SkipReason::BuildOutput => write!(f, "build output"),
+            SkipReason::NotIncluded => write!(f, "not matched by any include pattern"),
+            SkipReason::Excluded(pattern) => write!(f, "matched exclude pattern '{}'", pattern),
+            SkipReason::Hidden => write!(f, "hidden directory, not searched"),
+        }
+    }
+}
+
+/// 탐색 결과
+#[derive(Debug, Default)]
+pub struct ManifestDiscovery {
+    pub manifests: Vec<PathBuf>,
+    pub skipped: Vec<(PathBuf, SkipReason)>,
+}
+
+/// 빌드 디렉토리 안에서 생성된 결과물이 들어가는 하위 디렉토리
+const BUILD_OUTPUT_DIRS: [&str; 4] = ["intermediates", "outputs", "generated", "tmp"];
+
+/// `dir` 아래의 AndroidManifest.xml을 찾고, 제외한 파일은 이유와 함께 돌려준다.
+pub fn find_manifest_files(dir: &str, options: &DiscoveryOptions) -> ManifestDiscovery {
+    let mut discovery = ManifestDiscovery::default();
+    // 숨김 디렉토리(.gradle 캐시 등)는 들어가지 않고 디렉토리 하나로 건너뛴 목록에 남긴다. .git은 남기지 않는다
+    let mut hidden_dirs = Vec::new();
+    let manifests = walkdir::WalkDir::new(dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| {
+            let hidden = e.depth() > 0 && e.file_type().is_dir() && is_hidden(&e.file_name().to_string_lossy());
+            if hidden && e.file_name() != ".git" {
+                hidden_dirs.push(e.path().to_path_buf());
+            }
+            !hidden
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && e.file_name() == "AndroidManifest.xml");
+
+    for entry in manifests {
+        let path = entry.path();
+        let relative = path.strip_prefix(dir).unwrap_or(path);
+        match skip_reason(relative, options) {
+            Some(reason) => discovery.skipped.push((path.to_path_buf(), reason)),
+            None => discovery.manifests.push(path.to_path_buf()),
+        }
+    }
+    discovery.skipped.extend(hidden_dirs.into_iter().map(|path| (path, SkipReason::Hidden)));
+
+    for path in &options.extra_manifests {
+        let known = discovery.manifests.contains(path) || discovery.skipped.iter().any(|(p, _)| p == path);
+        if known || !path.is_file() {
+            continue;
+        }
+        let relative = path.strip_prefix(dir).unwrap_or(path);
+        match skip_reason(relative, options) {
+            Some(reason) => discovery.skipped.push((path.clone(), reason)),
+            None => discovery.manifests.push(path.clone()),
+        }
+    }
+    discovery
+}
+
+fn skip_reason(relative: &Path, options: &DiscoveryOptions) -> Option<SkipReason> {
+    if let Some(pattern) = options.exclude.iter().find(|p| p.matches_path(relative)) {
+        return Some(SkipReason::Excluded(pattern.as_str().to_string()));
+    }
+    if !options.include.is_empty() && !options.include.iter().any(|p| p.matches_path(relative)) {
+        return Some(SkipReason::NotIncluded);
+    }
+    if is_build_output(relative) {
+        return Some(SkipReason::BuildOutput);
+    }
+    match source_set(relative) {
+        Some(name) if !options.include_tests && is_test_source_set(&name) => Some(SkipReason::TestSourceSet(name)),
+        _ => None,
+    }
+}
+
+/// `<module>/src/<source set>/AndroidManifest.xml` 형태의 경로에서 소스 세트 이름을 구한다.
+///
+/// main, debug, release, 플레이버(free, freeDebug, ...) 등 Gradle 소스 세트 디렉토리 이름을 그대로 돌려주며,
+/// apktool 결과처럼 Gradle 구조가 아니면 `None`이다.
+pub fn source_set(manifest_path: &Path) -> Option<String> {
+    let parent = manifest_path.parent()?;
+    let grandparent = parent.parent()?;
+    if grandparent.file_name()? != "src" {
+        return None;
+    }
+    Some(parent.file_name()?.to_string_lossy().into_owned())
+}
+
+/// test, androidTest, testFixtures와 testDebug, androidTestFree 같은 변형 소스 세트
+pub fn is_test_source_set(name: &str) -> bool {
+    ["androidTest", "testFixtures", "test"].iter().any(|prefix| {
+        name.strip_prefix(prefix)
+            .is_some_and(|rest| rest.chars().next().is_none_or(|c| c.is_ascii_uppercase()))
+    })
+}
+
+fn is_build_output(relative: &Path) -> bool {
+    let names: Vec<_> = relative.components()
+        .filter_map(|c| match c {
+            PathComponent::Normal(name) => Some(name.to_string_lossy()),
+            _ => None,
+        })
+        .collect();
+    names.windows(2)
+        .any(|pair| pair[0] == "build" && BUILD_OUTPUT_DIRS.contains(&pair[1].as_ref()))
+}
+
+fn is_hidden(name: &str) -> bool {
+    name.starts_with('.')
+}
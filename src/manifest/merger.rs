@@ -0,0 +1,314 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+use crate::gradle::{GradleModule, GradleProject, ModuleKind};
+use crate::manifest::component::Component;
+use crate::manifest::parser::parse_manifest;
+use crate::manifest::resources::ValueResolver;
+
+/// 매니페스트 병합 설정
+#[derive(Debug, Clone)]
+pub struct MergeOptions<'a> {
+    /// 병합할 빌드 변형 (release, debug, freeDebug, ...)
+    pub variant: &'a str,
+    /// 빌드 결과물의 병합된 매니페스트가 있으면 소스 대신 사용
+    pub use_merged_manifests: bool,
+}
+
+/// Gradle 앱 모듈마다 Android 매니페스트 병합의 핵심 규칙을 재현해 컴포넌트를 합친다.
+///
+/// 우선순위는 변형 소스 세트(freeDebug > debug > free) > main > 의존하는 라이브러리 모듈 순이다.
+/// 앱에 포함되는 라이브러리의 컴포넌트는 앱의 패키지로 보고되고, 선택한 변형에 속하지 않는
+/// 소스 세트의 컴포넌트는 제외된다. Gradle 모듈에 속하지 않는 컴포넌트는 그대로 둔다.
+pub fn merge_project_components(
+    components: Vec<Component>,
+    projects: &[GradleProject],
+    options: &MergeOptions,
+) -> Vec<Component> {
+    let mut remaining = components;
+    let mut result = Vec::new();
+
+    for project in projects {
+        let (project_components, rest): (Vec<_>, Vec<_>) = remaining.into_iter()
+            .partition(|c| c.module.is_some() && c.manifest_path.starts_with(&project.root));
+        remaining = rest;
+
+        let apps: Vec<&GradleModule> = project.modules.iter()
+            .filter(|m| m.kind == ModuleKind::Application)
+            .collect();
+        let mut consumed = BTreeSet::new();
+        for app in &apps {
+            let modules = project.dependency_order(app);
+            consumed.extend(modules.iter().map(|m| m.path.clone()));
+
+            let merged = match find_merged_manifest(app, options) {
+                Some(path) => import_merged_manifest(project, app, &path, options),
+                None => merge_app(project, app, &modules, &project_components, options),
+            };
+            result.extend(merged);
+        }
+
+        // 어떤 앱에도 포함되지 않는 모듈(독립 라이브러리 등)은 병합하지 않고 보고한다
+        result.extend(project_components.into_iter()
+            .filter(|c| !consumed.contains(c.module.as_deref().unwrap_or_default())));
+    }
+
+    result.extend(remaining);
+    result
+}
+
+fn merge_app(
+    project: &GradleProject,
+    app: &GradleModule,
+    modules: &[&GradleModule],
+    components: &[Component],
+    options: &MergeOptions,
+) -> Vec<Component> {
+    let source_sets = variant_source_sets(options.variant);
+
+    // 우선순위가 높은 매니페스트의 컴포넌트부터 나열한다
+    let mut ordered = Vec::new();
+    let mut manifests = Vec::new();
+    for module in modules {
+        for source_set in &source_sets {
+            let declared: Vec<&Component> = components.iter()
+                .filter(|c| c.module.as_deref() == Some(module.path.as_str()))
+                .filter(|c| c.source_set.as_deref().unwrap_or("main") == source_set)
+                .collect();
+            if let Some(first) = declared.first() {
+                manifests.push(first.manifest_path.clone());
+            }
+            ordered.extend(declared);
+        }
+    }
+
+    let skipped = components.iter()
+        .filter(|c| modules.iter().any(|m| c.module.as_deref() == Some(m.path.as_str())))
+        .filter(|c| !source_sets.iter().any(|s| c.source_set.as_deref().unwrap_or("main") == s))
+        .count();
+    if skipped > 0 {
+        debug!("Skipped {} components of {} outside the '{}' variant", skipped, app.path, options.variant);
+    }
+
+    let package = ordered.iter()
+        .find(|c| c.module.as_deref() == Some(app.path.as_str()))
+        .map(|c| c.package.clone())
+        .or_else(|| app.application_id.clone())
+        .or_else(|| app.namespace.clone());
+    // 라이브러리 컴포넌트도 앱의 targetSdk로 실행된다
+    let target_sdk = app.target_sdk.or_else(|| ordered.iter()
+        .find(|c| c.module.as_deref() == Some(app.path.as_str()))
+        .and_then(|c| c.target_sdk));
+    // 라이브러리가 선언한 <permission>도 앱에 병합된다 (앞선 매니페스트가 우선)
+    let mut permission_levels = BTreeMap::new();
+    for component in &ordered {
+        for (permission, level) in &component.permission_levels {
+            permission_levels.entry(permission.clone()).or_insert_with(|| level.clone());
+        }
+    }
+    let source_roots = project.source_roots(app);
+
+    let mut merged = merge_ordered(ordered);
+    for component in &mut merged {
+        if let Some(package) = &package {
+            component.package = package.clone();
+        }
+        component.target_sdk = target_sdk;
+        component.permission_levels = permission_levels.clone();
+        component.source_roots = source_roots.clone();
+    }
+    // 라이브러리의 `${applicationId}`도 앱의 값으로 풀린다
+    let resolver = ValueResolver::for_module(project, app, &source_sets, package.as_deref().unwrap_or_default());
+    for component in &mut merged {
+        resolver.resolve_component(component);
+    }
+    info!("Merged {} manifests of {} ({} variant): {} components", manifests.len(), app.path, options.variant, merged.len());
+    merged
+}
+
+/// 우선순위 순으로 나열된 선언을 컴포넌트 단위로 병합한다.
+fn merge_ordered(ordered: Vec<&Component>) -> Vec<Component> {
+    let mut merged: Vec<Component> = Vec::new();
+    let mut removed = BTreeSet::new();
+
+    for component in ordered {
+        let key = (component.component_type.clone(), component.name.clone());
+        if removed.contains(&key) {
+            continue;
+        }
+        if component.tools_node.as_deref() == Some("remove") {
+            debug!("{} removed by tools:node=\"remove\" in {}", component.name, component.manifest_path.display());
+            removed.insert(key);
+            continue;
+        }
+
+        match merged.iter_mut().find(|c| c.component_type == key.0 && c.name == key.1) {
+            Some(higher) => merge_into(higher, component),
+            None => merged.push(component.clone()),
+        }
+    }
+
+    for component in &mut merged {
+        // tools:node="remove"로 표시된 intent-filter는 낮은 우선순위의 같은 필터를 지운 뒤 스스로도 빠진다
+        component.intent_filters.retain(|f| f.tools_node.as_deref() != Some("remove"));
+        component.refresh_intent_data();
+    }
+    merged
+}
+
+/// 우선순위가 낮은 선언(`lower`)을 높은 선언(`higher`)에 합친다.
+fn merge_into(higher: &mut Component, lower: &Component) {
+    if higher.tools_node.as_deref() == Some("replace") {
+        debug!("{} in {} replaced by tools:node=\"replace\"", lower.name, lower.manifest_path.display());
+        return;
+    }
+
+    // 높은 쪽에 없는 속성은 낮은 쪽에서 가져오고, 둘 다 있으면 높은 쪽이 이긴다
+    if lower.declared_attributes.contains("exported") {
+        if !higher.declared_attributes.contains("exported") {
+            higher.exported = lower.exported;
+            higher.declared_attributes.insert("exported".to_string());
+        } else if higher.exported != lower.exported && !higher.tools_replace.iter().any(|a| a == "exported") {
+            warn!("Conflicting android:exported for {} ({} vs {}); add tools:replace=\"android:exported\"",
+                higher.name, higher.manifest_path.display(), lower.manifest_path.display());
+        }
+    }
+    if lower.declared_attributes.contains("permission") {
+        if !higher.declared_attributes.contains("permission") {
+            higher.permissions = lower.permissions.clone();
+            higher.declared_attributes.insert("permission".to_string());
+        } else if higher.permissions != lower.permissions && !higher.tools_replace.iter().any(|a| a == "permission") {
+            warn!("Conflicting android:permission for {} ({} vs {}); add tools:replace=\"android:permission\"",
+                higher.name, higher.manifest_path.display(), lower.manifest_path.display());
+        }
+    }
+
+    if let Some(lower_provider) = &lower.provider {
+        let provider = higher.provider.get_or_insert_with(Default::default);
+        provider.merge_from(lower_provider);
+        for attribute in ["grantUriPermissions", "multiprocess"] {
+            if lower.declared_attributes.contains(attribute) && !higher.declared_attributes.contains(attribute) {
+                match attribute {
+                    "grantUriPermissions" => provider.grant_uri_permissions = lower_provider.grant_uri_permissions,
+                    _ => provider.multiprocess = lower_provider.multiprocess,
+                }
+                higher.declared_attributes.insert(attribute.to_string());
+            }
+        }
+    }
+
+    if let Some(lower_activity) = &lower.activity {
+        higher.activity.get_or_insert_with(Default::default).merge_from(lower_activity);
+    }
+
+    for filter in &lower.intent_filters {
+        let exists = higher.intent_filters.iter().any(|f| f.same_content(filter));
+        if !exists {
+            higher.intent_filters.push(filter.clone());
+        }
+    }
+    for meta_data in &lower.meta_data {
+        if !higher.meta_data.iter().any(|m| m.name == meta_data.name) {
+            higher.meta_data.push(meta_data.clone());
+        }
+    }
+    for permission in &lower.intent_filter_permissions {
+        if !higher.intent_filter_permissions.contains(permission) {
+            higher.intent_filter_permissions.push(permission.clone());
+        }
+    }
+    if !higher.merged_from.contains(&lower.manifest_path) && higher.manifest_path != lower.manifest_path {
+        higher.merged_from.push(lower.manifest_path.clone());
+    }
+}
+
+/// 변형 이름을 우선순위 순의 소스 세트 이름으로 푼다.
+///
+/// `freeStagingDebug`는 freeStagingDebug, debug, freeStaging, free, staging, main 순이다.
+pub fn variant_source_sets(variant: &str) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    for c in variant.chars() {
+        match parts.last_mut() {
+            Some(part) if !c.is_ascii_uppercase() => part.push(c),
+            _ => parts.push(c.to_ascii_lowercase().to_string()),
+        }
+    }
+
+    let mut source_sets = vec![variant.to_string()];
+    if parts.len() > 1 {
+        let (build_type, flavors) = parts.split_last().unwrap();
+        source_sets.push(build_type.clone());
+        if flavors.len() > 1 {
+            source_sets.push(camel_case(flavors));
+        }
+        source_sets.extend(flavors.iter().cloned());
+    }
+    source_sets.push("main".to_string());
+    source_sets.dedup();
+    source_sets
+}
+
+fn camel_case(parts: &[String]) -> String {
+    parts.iter()
+        .enumerate()
+        .map(|(i, part)| {
+            if i == 0 {
+                return part.clone();
+            }
+            let mut chars = part.chars();
+            chars.next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// `build/intermediates/merged_manifest(s)/**/<variant>/**/AndroidManifest.xml`
+fn find_merged_manifest(app: &GradleModule, options: &MergeOptions) -> Option<PathBuf> {
+    if !options.use_merged_manifests {
+        return None;
+    }
+    let intermediates = app.dir.join("build").join("intermediates");
+    let found = walkdir::WalkDir::new(&intermediates)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name() == "AndroidManifest.xml")
+        .map(|e| e.into_path())
+        .find(|path| {
+            let relative = path.strip_prefix(&intermediates).unwrap_or(path);
+            let mut names = relative.iter().map(|n| n.to_string_lossy());
+            let in_merged = names.next().is_some_and(|n| n.starts_with("merged_manifest"));
+            in_merged && relative.iter().any(|n| n == options.variant)
+        });
+    if found.is_none() {
+        warn!("No merged manifest for {} ({} variant) under {}; merging source manifests instead",
+            app.path, options.variant, intermediates.display());
+    }
+    found
+}
+
+fn import_merged_manifest(project: &GradleProject, app: &GradleModule, path: &Path, options: &MergeOptions) -> Vec<Component> {
+    info!("Using merged manifest for {}: {}", app.path, path.display());
+    let path = path.to_path_buf();
+    match parse_manifest(&path, None, app.namespace.as_deref(), app.application_id.as_deref()) {
+        Ok(mut components) => {
+            let source_roots = project.source_roots(app);
+            let package = components.first().map(|c| c.package.clone()).unwrap_or_default();
+            // 병합된 매니페스트에는 자리표시자가 이미 치환되어 있지만 리소스 참조는 남는다
+            let resolver = ValueResolver::for_module(project, app, &variant_source_sets(options.variant), &package);
+            for component in &mut components {
+                component.module = Some(app.path.clone());
+                component.source_set = Some(options.variant.to_string());
+                component.source_roots = source_roots.clone();
+                component.target_sdk = component.target_sdk.or(app.target_sdk);
+                resolver.resolve_component(component);
+            }
+            components
+        }
+        Err(e) => {
+            warn!("Failed to parse merged manifest {}: {}", path.display(), e);
+            Vec::new()
+        }
+    }
+}
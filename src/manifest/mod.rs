@@ -1,5 +1,11 @@
 pub mod component;
+pub mod discovery;
+pub mod merger;
 pub mod parser;
+pub mod resources;
+pub mod uri;
+pub mod xml_span;
 
-pub use parser::{find_manifest_files, parse_manifest};
+pub use discovery::{find_manifest_files, DiscoveryOptions};
+pub use parser::parse_manifest;
 pub use component::Component; 
\ No newline at end of file
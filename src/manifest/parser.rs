@@ -1,56 +1,75 @@
-use std::fs::File;
-use std::io::BufReader;
 use std::path::PathBuf;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet};
+use xml::common::Position;
 use xml::reader::{EventReader, XmlEvent};
-use crate::manifest::component::Component;
+use crate::manifest::component::{ActivityInfo, Component, DataElement, IntentFilter, MetaData, ProviderInfo};
+use crate::manifest::discovery::source_set;
+use crate::manifest::xml_span::{pretty_print, tag_end, LineIndex};
+use crate::permissions::protection_levels::normalize_protection_level;
 
-pub fn find_manifest_files(dir: &str) -> Vec<PathBuf> {
-    walkdir::WalkDir::new(dir)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| {
-            let path = e.path().to_string_lossy();
-            !path.contains("test") && e.file_name() == "AndroidManifest.xml"
-        })
-        .map(|e| e.path().to_path_buf())
-        .collect()
-}
+/// `tools:` 속성(매니페스트 병합 규칙)의 네임스페이스
+pub const TOOLS_NAMESPACE: &str = "http://schemas.android.com/tools";
 
-pub fn parse_manifest(file_path: &PathBuf, package_filter: Option<&str>) -> Result<Vec<Component>, Box<dyn std::error::Error>> {
-    let file = File::open(file_path)?;
-    let file = BufReader::new(file);
-    let parser = EventReader::new(file);
+/// 매니페스트에서 컴포넌트를 읽는다.
+///
+/// `module_namespace`와 `application_id`는 Gradle 모듈 설정 값이다. 매니페스트에 `package` 속성이 없으면
+/// `module_namespace`로 상대 클래스 이름을 풀고, 컴포넌트의 패키지는 설치되는 이름인 `application_id`를 우선한다.
+pub fn parse_manifest(
+    file_path: &PathBuf,
+    package_filter: Option<&str>,
+    module_namespace: Option<&str>,
+    application_id: Option<&str>,
+) -> Result<Vec<Component>, Box<dyn std::error::Error>> {
+    // 컴포넌트의 원본 XML을 잘라내기 위해 파일 전체를 읽어 둔다
+    let contents = std::fs::read_to_string(file_path)?;
+    let line_index = LineIndex::new(&contents);
+    let mut parser = EventReader::new(contents.as_bytes());
+    let mut namespaces = BTreeMap::new();
+    let mut component_start = None;
     
     let mut components = Vec::new();
     let mut current_package = String::new();
     let mut current_shared_user_id = None;
+    let mut target_sdk = None;
+    // 최상위 <permission> 선언의 보호 수준
+    let mut permission_levels = BTreeMap::new();
+    // <application>의 taskAffinity, allowTaskReparenting (activity의 기본값)
+    let mut application = ActivityInfo::default();
     let mut current_component = Option::<Component>::None;
-    let mut current_actions = HashSet::new();
-    let mut current_categories = HashSet::new();
-    let mut current_data_schemes = HashSet::new();
-    let mut current_data_hosts = HashSet::new();
-    let mut current_data_paths = HashSet::new();
-    let mut current_mime_types = HashSet::new();
+    let mut current_actions = BTreeSet::new();
+    let mut current_categories = BTreeSet::new();
+    let mut current_data_schemes = BTreeSet::new();
+    let mut current_data_hosts = BTreeSet::new();
+    let mut current_data_paths = BTreeSet::new();
+    let mut current_mime_types = BTreeSet::new();
     let _current_permissions: Vec<String> = Vec::new();
     let mut current_intent_filter_permissions = Vec::new();
     let mut in_intent_filter = false;
+    let mut current_filter = IntentFilter::default();
+    let mut current_filters = Vec::new();
     let mut _depth = 0;
-    let mut current_line = 0;
-    let mut current_xml = String::new();
 
     // 매니페스트 디렉토리 경로 가져오기
     let manifest_dir = file_path.parent()
-        .ok_or_else(|| "Failed to get manifest directory")?
+        .ok_or("Failed to get manifest directory")?
         .to_path_buf();
+    let manifest_source_set = source_set(file_path);
+
+    loop {
+        let event = parser.next();
+        // 이벤트가 시작된 위치 ('<'의 위치, 0부터 시작)
+        let position = parser.position();
+        let current_line = position.row as usize + 1;
+        let current_column = position.column as usize + 1;
 
-    for event in parser {
         match event {
-            Ok(XmlEvent::StartElement { name, attributes, .. }) => {
+            Ok(XmlEvent::StartElement { name, attributes, namespace }) => {
                 _depth += 1;
-                current_line += 1;
                 match name.local_name.as_str() {
                     "manifest" => {
+                        namespaces = namespace.0.into_iter()
+                            .filter(|(prefix, _)| !prefix.is_empty() && prefix != "xml" && prefix != "xmlns")
+                            .collect();
                         for attr in attributes {
                             match attr.name.local_name.as_str() {
                                 "package" => current_package = attr.value,
@@ -59,100 +78,226 @@ pub fn parse_manifest(file_path: &PathBuf, package_filter: Option<&str>) -> Resu
                             }
                         }
                     }
+                    "uses-sdk" => {
+                        if let Some(attr) = attributes.iter().find(|a| a.name.local_name == "targetSdkVersion") {
+                            target_sdk = attr.value.trim().parse().ok();
+                        }
+                    }
+                    "application" => {
+                        for attr in attributes.iter().filter(|a| a.name.namespace.as_deref() != Some(TOOLS_NAMESPACE)) {
+                            match attr.name.local_name.as_str() {
+                                "taskAffinity" => application.application_task_affinity = Some(attr.value.clone()),
+                                "allowTaskReparenting" => application.application_allow_task_reparenting = Some(attr.value == "true"),
+                                _ => {}
+                            }
+                        }
+                    }
                     "activity" | "service" | "receiver" | "provider" => {
                         let component_type = name.local_name.clone();
                         let mut component_name = String::new();
                         let mut exported = false;
-                        current_xml = format!("<{}", name.local_name);
+                        let mut permissions = Vec::new();
+                        let mut tools_node = None;
+                        let mut tools_replace = Vec::new();
+                        let mut declared_attributes = BTreeSet::new();
 
                         for attr in &attributes {
+                            if attr.name.namespace.as_deref() == Some(TOOLS_NAMESPACE) {
+                                match attr.name.local_name.as_str() {
+                                    "node" => tools_node = Some(attr.value.clone()),
+                                    "replace" => tools_replace = attr.value.split(',')
+                                        .map(|a| a.trim().trim_start_matches("android:").to_string())
+                                        .collect(),
+                                    _ => {}
+                                }
+                                continue;
+                            }
+                            declared_attributes.insert(attr.name.local_name.clone());
                             match attr.name.local_name.as_str() {
                                 "name" => component_name = attr.value.clone(),
                                 "exported" => exported = attr.value == "true",
+                                "permission" => permissions.push(attr.value.clone()),
                                 _ => {}
                             }
-                            current_xml.push_str(&format!(" {}={}", attr.name.local_name, attr.value));
                         }
-                        current_xml.push('>');
+
+                        let provider = (component_type == "provider").then(|| {
+                            let mut provider = ProviderInfo::default();
+                            for attr in attributes.iter().filter(|a| a.name.namespace.as_deref() != Some(TOOLS_NAMESPACE)) {
+                                provider.set_attribute(&attr.name.local_name, &attr.value);
+                            }
+                            provider
+                        });
+                        let activity = (component_type == "activity").then(|| {
+                            let mut activity = application.clone();
+                            for attr in attributes.iter().filter(|a| a.name.namespace.as_deref() != Some(TOOLS_NAMESPACE)) {
+                                activity.set_attribute(&attr.name.local_name, &attr.value);
+                            }
+                            activity
+                        });
 
                         if !component_name.is_empty() {
+                            let base_package = match module_namespace {
+                                Some(namespace) if current_package.is_empty() => namespace,
+                                _ => current_package.as_str(),
+                            };
                             let full_name = if component_name.starts_with('.') {
-                                format!("{}{}", current_package, component_name)
+                                format!("{}{}", base_package, component_name)
                             } else {
                                 component_name
                             };
 
                             let component = Component {
                                 name: full_name.clone(),
-                                package: current_package.clone(),
+                                package: application_id.unwrap_or(base_package).to_string(),
                                 component_type,
                                 exported,
                                 manifest_path: file_path.clone(),
                                 manifest_line: current_line,
+                                manifest_column: current_column,
+                                source_set: manifest_source_set.clone(),
+                                module: None,
+                                source_roots: Vec::new(),
                                 manifest_dir: manifest_dir.clone(),
                                 class_name: full_name,
-                                actions: HashSet::new(),
-                                categories: HashSet::new(),
-                                data_schemes: HashSet::new(),
-                                data_hosts: HashSet::new(),
-                                data_paths: HashSet::new(),
-                                data_mime_types: HashSet::new(),
-                                permissions: Vec::new(),
+                                actions: BTreeSet::new(),
+                                categories: BTreeSet::new(),
+                                data_schemes: BTreeSet::new(),
+                                data_hosts: BTreeSet::new(),
+                                data_paths: BTreeSet::new(),
+                                data_mime_types: BTreeSet::new(),
+                                permissions,
                                 intent_filter_permissions: Vec::new(),
                                 shared_user_id: current_shared_user_id.clone(),
-                                xml_element: Some(current_xml.clone()),
+                                xml_element: None,
+                                xml_normalized: None,
+                                intent_filters: Vec::new(),
+                                tools_node,
+                                tools_replace,
+                                declared_attributes,
+                                merged_from: Vec::new(),
+                                provider,
+                                meta_data: Vec::new(),
+                                dynamic: None,
+                                activity,
+                                target_sdk,
+                                permission_levels: BTreeMap::new(),
                             };
                             current_component = Some(component);
+                            component_start = line_index.offset(&contents, current_line, current_column);
+
+                            // 이전 컴포넌트의 intent-filter 정보가 남지 않도록 초기화
+                            current_actions.clear();
+                            current_categories.clear();
+                            current_data_schemes.clear();
+                            current_data_hosts.clear();
+                            current_data_paths.clear();
+                            current_mime_types.clear();
+                            current_intent_filter_permissions.clear();
+                            current_filters.clear();
                         }
                     }
                     "intent-filter" => {
                         in_intent_filter = true;
-                        current_actions.clear();
-                        current_categories.clear();
-                        current_data_schemes.clear();
-                        current_data_hosts.clear();
-                        current_data_paths.clear();
-                        current_mime_types.clear();
-                        current_intent_filter_permissions.clear();
+                        current_filter = IntentFilter {
+                            line: current_line,
+                            column: current_column,
+                            tools_node: attributes.iter()
+                                .find(|a| a.name.namespace.as_deref() == Some(TOOLS_NAMESPACE) && a.name.local_name == "node")
+                                .map(|a| a.value.clone()),
+                            auto_verify: attributes.iter()
+                                .any(|a| a.name.local_name == "autoVerify" && a.value == "true"),
+                            ..IntentFilter::default()
+                        };
                     }
-                    "action" => {
-                        if in_intent_filter {
-                            for attr in attributes {
-                                if attr.name.local_name == "name" {
-                                    current_actions.insert(attr.value);
-                                }
+                    "action" if in_intent_filter => {
+                        for attr in attributes {
+                            if attr.name.local_name == "name" {
+                                current_filter.actions.push(attr.value.clone());
+                                current_actions.insert(attr.value);
+                            }
+                        }
+                    }
+                    "category" if in_intent_filter => {
+                        for attr in attributes {
+                            if attr.name.local_name == "name" {
+                                current_filter.categories.push(attr.value.clone());
+                                current_categories.insert(attr.value);
                             }
                         }
                     }
-                    "category" => {
-                        if in_intent_filter {
-                            for attr in attributes {
-                                if attr.name.local_name == "name" {
-                                    current_categories.insert(attr.value);
+                    "data" if in_intent_filter => {
+                        let mut data = DataElement {
+                            line: current_line,
+                            column: current_column,
+                            ..DataElement::default()
+                        };
+                        for attr in attributes {
+                            match attr.name.local_name.as_str() {
+                                "scheme" => {
+                                    data.scheme = Some(attr.value.clone());
+                                    current_data_schemes.insert(attr.value);
+                                }
+                                "host" => {
+                                    data.host = Some(attr.value.clone());
+                                    current_data_hosts.insert(attr.value);
+                                }
+                                "path" => {
+                                    data.path = Some(attr.value.clone());
+                                    current_data_paths.insert(attr.value);
+                                }
+                                "mimeType" => {
+                                    data.mime_type = Some(attr.value.clone());
+                                    current_mime_types.insert(attr.value);
+                                }
+                                // port, pathPrefix, pathPattern, ssp*, fragment*, ...
+                                name => {
+                                    data.set_attribute(name, attr.value);
                                 }
                             }
                         }
+                        current_filter.data.push(data);
                     }
-                    "data" => {
-                        if in_intent_filter {
-                            for attr in attributes {
-                                match attr.name.local_name.as_str() {
-                                    "scheme" => current_data_schemes.insert(attr.value),
-                                    "host" => current_data_hosts.insert(attr.value),
-                                    "path" => current_data_paths.insert(attr.value),
-                                    "mimeType" => current_mime_types.insert(attr.value),
-                                    _ => false,
-                                };
+                    "grant-uri-permission" | "path-permission" if !in_intent_filter => {
+                        if let Some(provider) = current_component.as_mut().and_then(|c| c.provider.as_mut()) {
+                            let attributes = attributes.iter().map(|a| (a.name.local_name.as_str(), a.value.as_str()));
+                            if name.local_name == "grant-uri-permission" {
+                                provider.add_grant_uri_permission(attributes);
+                            } else {
+                                provider.add_path_permission(attributes);
                             }
                         }
                     }
-                    "permission" => {
-                        if in_intent_filter {
-                            for attr in attributes {
-                                if attr.name.local_name == "name" {
-                                    current_intent_filter_permissions.push(attr.value);
+                    "meta-data" if !in_intent_filter => {
+                        if let Some(component) = current_component.as_mut() {
+                            let mut meta_data = MetaData::default();
+                            for attr in attributes.into_iter().filter(|a| a.name.namespace.as_deref() != Some(TOOLS_NAMESPACE)) {
+                                match attr.name.local_name.as_str() {
+                                    "name" => meta_data.name = attr.value,
+                                    "value" => meta_data.value = Some(attr.value),
+                                    "resource" => meta_data.resource = Some(attr.value),
+                                    _ => {}
                                 }
                             }
+                            if !meta_data.name.is_empty() {
+                                component.meta_data.push(meta_data);
+                            }
+                        }
+                    }
+                    "permission" if current_component.is_none() => {
+                        let declared = |local: &str| attributes.iter()
+                            .find(|a| a.name.local_name == local && a.name.namespace.as_deref() != Some(TOOLS_NAMESPACE))
+                            .map(|a| a.value.as_str());
+                        if let Some(permission) = declared("name") {
+                            let level = normalize_protection_level(declared("protectionLevel"));
+                            permission_levels.insert(permission.to_string(), level.to_string());
+                        }
+                    }
+                    "permission" if in_intent_filter => {
+                        for attr in attributes {
+                            if attr.name.local_name == "name" {
+                                current_intent_filter_permissions.push(attr.value);
+                            }
                         }
                     }
                     _ => {}
@@ -163,13 +308,23 @@ pub fn parse_manifest(file_path: &PathBuf, package_filter: Option<&str>) -> Resu
                 match name.local_name.as_str() {
                     "activity" | "service" | "receiver" | "provider" => {
                         if let Some(mut component) = current_component.take() {
+                            // 여는 태그의 '<'부터 닫는 태그(또는 '/>')의 '>'까지가 원본 범위
+                            let span = component_start.take().and_then(|start| {
+                                let end_tag = line_index.offset(&contents, current_line, current_column)?;
+                                Some(&contents[start..tag_end(&contents, end_tag)?])
+                            });
+                            if let Some(xml) = span {
+                                component.xml_normalized = pretty_print(xml, &namespaces);
+                                component.xml_element = Some(xml.to_string());
+                            }
                             component.actions = current_actions.clone();
                             component.categories = current_categories.clone();
                             component.data_schemes = current_data_schemes.clone();
                             component.data_hosts = current_data_hosts.clone();
                             component.data_paths = current_data_paths.clone();
                             component.data_mime_types = current_mime_types.clone();
-                            component.intent_filter_permissions = current_intent_filter_permissions.iter().cloned().collect();
+                            component.intent_filter_permissions = current_intent_filter_permissions.clone();
+                            component.intent_filters = current_filters.clone();
 
                             if let Some(package) = &package_filter {
                                 if component.package == *package {
@@ -182,14 +337,24 @@ pub fn parse_manifest(file_path: &PathBuf, package_filter: Option<&str>) -> Resu
                     }
                     "intent-filter" => {
                         in_intent_filter = false;
+                        current_filters.push(std::mem::take(&mut current_filter));
                     }
                     _ => {}
                 }
             }
+            Ok(XmlEvent::EndDocument) => break,
             Err(e) => return Err(Box::new(e)),
             _ => {}
         }
     }
 
+    // <uses-sdk>와 <permission>이 <application> 뒤에 있어도 모든 컴포넌트에 적용한다
+    for component in &mut components {
+        if target_sdk.is_some() {
+            component.target_sdk = target_sdk;
+        }
+        component.permission_levels = permission_levels.clone();
+    }
+
     Ok(components)
 } 
\ No newline at end of file
@@ -0,0 +1,293 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+use xml::common::Position;
+use xml::reader::{EventReader, XmlEvent};
+use crate::gradle::{self, GradleModule, GradleProject};
+use crate::manifest::component::{Component, FileProviderPath};
+use crate::manifest::merger::variant_source_sets;
+
+/// 풀 수 없는 값에 붙이는 표시. 셸에서 따옴표 없이도 안전한 문자만 쓴다.
+const UNRESOLVED_PREFIX: &str = "__unresolved_";
+const MAX_REFERENCE_DEPTH: usize = 8;
+
+/// 매니페스트 속성의 `${placeholder}`와 `@string/name` 참조를 실제 값으로 바꾼다.
+#[derive(Debug, Default)]
+pub struct ValueResolver {
+    placeholders: BTreeMap<String, String>,
+    strings: BTreeMap<String, String>,
+    /// 읽은 순서(우선순위)대로의 res 디렉토리
+    res_dirs: Vec<PathBuf>,
+}
+
+impl ValueResolver {
+    /// Gradle 모듈 기준: 모듈과 의존 모듈의 manifestPlaceholders, `applicationId`,
+    /// 그리고 변형 소스 세트 우선순위에 따른 `res/values*/strings.xml`
+    pub fn for_module(project: &GradleProject, module: &GradleModule, source_sets: &[String], application_id: &str) -> Self {
+        let modules = project.dependency_order(module);
+        let mut resolver = Self::default();
+        for module in &modules {
+            for (name, value) in &module.manifest_placeholders {
+                resolver.placeholders.entry(name.clone()).or_insert_with(|| value.clone());
+            }
+        }
+        resolver.placeholders.insert("applicationId".to_string(), application_id.to_string());
+        resolver.placeholders.entry("packageName".to_string()).or_insert_with(|| application_id.to_string());
+
+        for module in &modules {
+            for source_set in source_sets {
+                resolver.load_res_dir(&module.dir.join("src").join(source_set).join("res"));
+            }
+        }
+        resolver
+    }
+
+    /// Gradle 모듈이 아닌 경우 (apktool 결과 등): 매니페스트 옆의 `res/`
+    pub fn for_manifest_dir(manifest_dir: &Path, package: &str) -> Self {
+        let mut resolver = Self::default();
+        resolver.placeholders.insert("applicationId".to_string(), package.to_string());
+        resolver.placeholders.insert("packageName".to_string(), package.to_string());
+        resolver.load_res_dir(&manifest_dir.join("res"));
+        resolver
+    }
+
+    /// 이미 읽은 값이 우선한다. 한 res 디렉토리 안에서는 한정자 없는 `values/`가 먼저다.
+    fn load_res_dir(&mut self, res_dir: &Path) {
+        let Ok(entries) = std::fs::read_dir(res_dir) else {
+            return;
+        };
+        self.res_dirs.push(res_dir.to_path_buf());
+        let mut values_dirs: Vec<PathBuf> = entries.filter_map(Result::ok)
+            .map(|e| e.path())
+            .filter(|p| p.is_dir())
+            .filter(|p| p.file_name().is_some_and(|n| n == "values" || n.to_string_lossy().starts_with("values-")))
+            .collect();
+        values_dirs.sort_by_key(|p| (p.file_name().is_some_and(|n| n != "values"), p.clone()));
+
+        for values_dir in values_dirs {
+            let Ok(files) = std::fs::read_dir(&values_dir) else {
+                continue;
+            };
+            let mut files: Vec<PathBuf> = files.filter_map(Result::ok)
+                .map(|e| e.path())
+                .filter(|p| p.extension().is_some_and(|e| e == "xml"))
+                .collect();
+            files.sort();
+            for file in files {
+                for (name, value) in read_strings(&file) {
+                    self.strings.entry(name).or_insert(value);
+                }
+            }
+        }
+    }
+
+    /// 값 안의 모든 참조를 푼다. 풀 수 없는 참조는 `__unresolved_<name>__`으로 바꾼다.
+    pub fn resolve(&self, value: &str) -> String {
+        self.resolve_depth(value, 0)
+    }
+
+    fn resolve_depth(&self, value: &str, depth: usize) -> String {
+        if let Some(name) = value.strip_prefix("@string/") {
+            return match self.strings.get(name) {
+                Some(resolved) if depth < MAX_REFERENCE_DEPTH => self.resolve_depth(resolved, depth + 1),
+                _ => unresolved(&format!("string_{}", name), value),
+            };
+        }
+
+        let mut result = String::new();
+        let mut rest = value;
+        while let Some(start) = rest.find("${") {
+            let Some(end) = rest[start..].find('}') else {
+                break;
+            };
+            let name = &rest[start + 2..start + end];
+            result.push_str(&rest[..start]);
+            match self.placeholders.get(name) {
+                Some(resolved) if depth < MAX_REFERENCE_DEPTH => result.push_str(&self.resolve_depth(resolved, depth + 1)),
+                _ => result.push_str(&unresolved(name, &rest[start..start + end + 1])),
+            }
+            rest = &rest[start + end + 1..];
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// `@xml/name` 리소스 파일. res 디렉토리 우선순위를 따르고, 한 디렉토리 안에서는 한정자 없는 `xml/`가 먼저다.
+    pub fn xml_resource(&self, reference: &str) -> Option<PathBuf> {
+        let name = reference.strip_prefix("@xml/")?;
+        self.res_dirs.iter().find_map(|res_dir| {
+            let mut xml_dirs: Vec<PathBuf> = std::fs::read_dir(res_dir).ok()?
+                .filter_map(Result::ok)
+                .map(|e| e.path())
+                .filter(|p| p.file_name().is_some_and(|n| n == "xml" || n.to_string_lossy().starts_with("xml-")))
+                .collect();
+            xml_dirs.sort_by_key(|p| (p.file_name().is_some_and(|n| n != "xml"), p.clone()));
+            xml_dirs.into_iter()
+                .map(|dir| dir.join(format!("{}.xml", name)))
+                .find(|file| file.is_file())
+        })
+    }
+
+    /// 권한과 intent-filter의 모든 값을 푼다.
+    pub fn resolve_component(&self, component: &mut Component) {
+        let resolve_all = |values: &mut Vec<String>| {
+            for value in values.iter_mut() {
+                if needs_resolution(value) {
+                    *value = self.resolve(value);
+                }
+            }
+        };
+        resolve_all(&mut component.permissions);
+        resolve_all(&mut component.intent_filter_permissions);
+        for filter in &mut component.intent_filters {
+            resolve_all(&mut filter.actions);
+            resolve_all(&mut filter.categories);
+            for data in &mut filter.data {
+                for value in data.values_mut() {
+                    if needs_resolution(value) {
+                        *value = self.resolve(value);
+                    }
+                }
+            }
+        }
+        for value in component.provider.iter_mut().flat_map(|p| p.values_mut())
+            .chain(component.activity.iter_mut().flat_map(|a| a.values_mut()))
+            .chain(component.meta_data.iter_mut().flat_map(|m| &mut m.value))
+        {
+            if needs_resolution(value) {
+                *value = self.resolve(value);
+            }
+        }
+        if let (Some(reference), Some(provider)) = (component.file_provider_paths(), component.provider.as_ref()) {
+            if provider.file_paths_resource.is_none() {
+                match self.xml_resource(reference) {
+                    Some(path) => {
+                        let file_paths = read_file_provider_paths(&path);
+                        let provider = component.provider.get_or_insert_with(Default::default);
+                        provider.file_paths = file_paths;
+                        provider.file_paths_resource = Some(path);
+                    }
+                    None => warn!("Could not find {} referenced by {}", reference, component.name),
+                }
+            }
+        }
+        component.refresh_intent_data();
+    }
+}
+
+/// 아직 참조가 남은 컴포넌트의 값을 푼다 (병합되지 않은 모듈, Gradle 밖의 매니페스트).
+/// 이미 풀린 값은 건드리지 않으므로 병합 뒤에 다시 호출해도 된다.
+pub fn resolve_components(components: &mut [Component], projects: &[GradleProject], variant: &str) {
+    let source_sets = variant_source_sets(variant);
+    let mut resolvers: HashMap<(PathBuf, String), ValueResolver> = HashMap::new();
+    for component in components.iter_mut().filter(|c| has_references(c)) {
+        let module = gradle::module_for_manifest(projects, &component.manifest_path);
+        let key = match module {
+            Some((_, module)) => (module.dir.clone(), component.package.clone()),
+            None => (component.manifest_dir.clone(), component.package.clone()),
+        };
+        let resolver = resolvers.entry(key).or_insert_with(|| match module {
+            Some((project, module)) => ValueResolver::for_module(project, module, &source_sets, &component.package),
+            None => ValueResolver::for_manifest_dir(&component.manifest_dir, &component.package),
+        });
+        resolver.resolve_component(component);
+    }
+}
+
+fn has_references(component: &Component) -> bool {
+    component.permissions.iter().chain(&component.intent_filter_permissions).any(|v| needs_resolution(v))
+        || component.intent_filters.iter().any(|f| {
+            f.actions.iter().chain(&f.categories).any(|v| needs_resolution(v))
+                || f.data.iter().flat_map(|d| d.values()).any(|v| needs_resolution(v))
+        })
+        || component.provider.iter().flat_map(|p| p.values()).any(|v| needs_resolution(v))
+        || component.activity.iter().flat_map(|a| a.values()).any(|v| needs_resolution(v))
+        || component.meta_data.iter().flat_map(|m| &m.value).any(|v| needs_resolution(v))
+        || (component.file_provider_paths().is_some()
+            && component.provider.as_ref().is_some_and(|p| p.file_paths_resource.is_none()))
+}
+
+fn needs_resolution(value: &str) -> bool {
+    value.contains("${") || value.starts_with("@string/")
+}
+
+fn unresolved(name: &str, original: &str) -> String {
+    warn!("Could not resolve {} in manifest attribute", original);
+    format!("{}{}__", UNRESOLVED_PREFIX, name)
+}
+
+/// `<string name="...">` 값. 따옴표로 감싼 값과 `\'`, `\"`, `\n` 이스케이프를 푼다.
+fn read_strings(path: &Path) -> Vec<(String, String)> {
+    let Ok(file) = std::fs::File::open(path) else {
+        return Vec::new();
+    };
+    let mut strings = Vec::new();
+    let mut current: Option<(String, String)> = None;
+    for event in EventReader::new(std::io::BufReader::new(file)) {
+        match event {
+            Ok(XmlEvent::StartElement { name, attributes, .. }) if name.local_name == "string" => {
+                current = attributes.iter()
+                    .find(|a| a.name.local_name == "name")
+                    .map(|a| (a.value.clone(), String::new()));
+            }
+            Ok(XmlEvent::Characters(text)) | Ok(XmlEvent::CData(text)) => {
+                if let Some((_, value)) = &mut current {
+                    value.push_str(&text);
+                }
+            }
+            Ok(XmlEvent::EndElement { name }) if name.local_name == "string" => {
+                if let Some((name, value)) = current.take() {
+                    strings.push((name, unescape(value.trim())));
+                }
+            }
+            Err(e) => {
+                debug!("Failed to read string resources {}: {}", path.display(), e);
+                break;
+            }
+            _ => {}
+        }
+    }
+    strings
+}
+
+/// FileProvider `<paths>` XML의 항목
+fn read_file_provider_paths(path: &Path) -> Vec<FileProviderPath> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let mut parser = EventReader::new(contents.as_bytes());
+    let mut paths = Vec::new();
+    loop {
+        let event = parser.next();
+        let line = parser.position().row as usize + 1;
+        match event {
+            Ok(XmlEvent::StartElement { name, attributes, .. }) if name.local_name.ends_with("-path") => {
+                let attribute = |key: &str| attributes.iter()
+                    .find(|a| a.name.local_name == key)
+                    .map(|a| a.value.clone())
+                    .unwrap_or_default();
+                paths.push(FileProviderPath {
+                    kind: name.local_name,
+                    name: attribute("name"),
+                    path: attribute("path"),
+                    line,
+                });
+            }
+            Ok(XmlEvent::EndDocument) => break,
+            Err(e) => {
+                debug!("Failed to read FileProvider paths {}: {}", path.display(), e);
+                break;
+            }
+            _ => {}
+        }
+    }
+    paths
+}
+
+fn unescape(value: &str) -> String {
+    let value = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')).unwrap_or(value);
+    value.replace("\\'", "'")
+        .replace("\\\"", "\"")
+        .replace("\\n", "\n")
+        .replace("\\@", "@")
+}
@@ -0,0 +1,389 @@
+use tracing::debug;
+use crate::manifest::component::{Component, DataElement, IntentFilter};
+
+/// 컴포넌트 하나에서 만들 예시 URI의 최대 개수
+const MAX_EXAMPLE_URIS: usize = 32;
+/// `.*`처럼 아무 문자열이나 허용하는 자리에 채울 값
+const ANY_TEXT: &str = "example";
+
+/// android.os.PatternMatcher의 매칭 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternKind {
+    Literal,
+    Prefix,
+    /// `pathPattern`: `.`은 아무 문자, `*`는 앞 문자의 0회 이상 반복
+    SimpleGlob,
+    /// `pathAdvancedPattern`: 문자 집합 `[a-z]`, `+`, `{n,m}`까지 지원
+    AdvancedGlob,
+    Suffix,
+}
+
+impl PatternKind {
+    /// 매니페스트 속성 이름의 접미사 (`path` + `Prefix`)
+    pub fn attribute_suffix(&self) -> &'static str {
+        match self {
+            PatternKind::Literal => "",
+            PatternKind::Prefix => "Prefix",
+            PatternKind::SimpleGlob => "Pattern",
+            PatternKind::AdvancedGlob => "AdvancedPattern",
+            PatternKind::Suffix => "Suffix",
+        }
+    }
+}
+
+/// path, ssp, fragment 속성 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UriPattern {
+    pub kind: PatternKind,
+    pub value: String,
+}
+
+impl UriPattern {
+    pub fn new(kind: PatternKind, value: &str) -> Self {
+        // 매니페스트에서는 `\\.`처럼 역슬래시를 두 번 써야 패턴의 `\.`이 된다
+        let value = match kind {
+            PatternKind::SimpleGlob | PatternKind::AdvancedGlob => value.replace("\\\\", "\\"),
+            _ => value.to_string(),
+        };
+        Self { kind, value }
+    }
+
+    pub fn matches(&self, text: &str) -> bool {
+        match self.kind {
+            PatternKind::Literal => text == self.value,
+            PatternKind::Prefix => text.starts_with(&self.value),
+            PatternKind::Suffix => text.ends_with(&self.value),
+            PatternKind::SimpleGlob | PatternKind::AdvancedGlob => {
+                let text: Vec<char> = text.chars().collect();
+                match_tokens(&self.tokens(), &text)
+            }
+        }
+    }
+
+    /// 패턴에 맞는 구체적인 값 하나
+    pub fn example(&self) -> String {
+        let example = match self.kind {
+            PatternKind::Literal => self.value.clone(),
+            // path 접두사는 `/item` → `/item/example`처럼 경로 단위로 잇는다
+            PatternKind::Prefix if self.value.starts_with('/') && !self.value.ends_with('/') => {
+                format!("{}/{}", self.value, ANY_TEXT)
+            }
+            PatternKind::Prefix => format!("{}{}", self.value, ANY_TEXT),
+            PatternKind::Suffix => format!("{}{}", ANY_TEXT, self.value),
+            PatternKind::SimpleGlob | PatternKind::AdvancedGlob => {
+                self.tokens().iter().map(Token::example).collect()
+            }
+        };
+        if !self.matches(&example) {
+            debug!("Example '{}' does not match pattern '{}'", example, self.value);
+        }
+        example
+    }
+
+    fn tokens(&self) -> Vec<Token> {
+        match self.kind {
+            PatternKind::AdvancedGlob => parse_advanced_glob(&self.value),
+            _ => parse_simple_glob(&self.value),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Atom {
+    Any,
+    Char(char),
+    Set { negated: bool, ranges: Vec<(char, char)> },
+}
+
+impl Atom {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Atom::Any => true,
+            Atom::Char(expected) => c == *expected,
+            Atom::Set { negated, ranges } => ranges.iter().any(|(low, high)| (*low..=*high).contains(&c)) != *negated,
+        }
+    }
+
+    fn example(&self) -> char {
+        match self {
+            Atom::Any => 'x',
+            Atom::Char(c) => *c,
+            Atom::Set { negated: false, ranges } => ranges.first().map(|(low, _)| *low).unwrap_or('x'),
+            Atom::Set { negated: true, .. } => "xa0_-".chars().find(|c| self.matches(*c)).unwrap_or('x'),
+        }
+    }
+}
+
+/// 반복 횟수가 붙은 원자. `max`가 None이면 제한 없음
+#[derive(Debug, Clone)]
+struct Token {
+    atom: Atom,
+    min: usize,
+    max: Option<usize>,
+}
+
+impl Token {
+    fn example(&self) -> String {
+        if matches!(self.atom, Atom::Any) && self.max.is_none() {
+            return ANY_TEXT.to_string();
+        }
+        let count = self.min.max(1).min(self.max.unwrap_or(usize::MAX));
+        std::iter::repeat_n(self.atom.example(), count).collect()
+    }
+}
+
+fn parse_simple_glob(pattern: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        let atom = match c {
+            '.' => Atom::Any,
+            '\\' => Atom::Char(chars.next().unwrap_or('\\')),
+            c => Atom::Char(c),
+        };
+        let repeated = chars.next_if_eq(&'*').is_some();
+        tokens.push(Token { atom, min: usize::from(!repeated), max: (!repeated).then_some(1) });
+    }
+    tokens
+}
+
+fn parse_advanced_glob(pattern: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        let atom = match c {
+            '.' => Atom::Any,
+            '\\' => Atom::Char(chars.next().unwrap_or('\\')),
+            '[' => {
+                let negated = chars.next_if_eq(&'^').is_some();
+                let mut ranges = Vec::new();
+                while let Some(c) = chars.next() {
+                    let low = match c {
+                        ']' => break,
+                        '\\' => chars.next().unwrap_or('\\'),
+                        c => c,
+                    };
+                    let high = if chars.peek() == Some(&'-') {
+                        chars.next();
+                        match chars.next() {
+                            Some('\\') => chars.next().unwrap_or('\\'),
+                            Some(']') | None => {
+                                ranges.push((low, low));
+                                ranges.push(('-', '-'));
+                                break;
+                            }
+                            Some(high) => high,
+                        }
+                    } else {
+                        low
+                    };
+                    ranges.push((low, high));
+                }
+                Atom::Set { negated, ranges }
+            }
+            c => Atom::Char(c),
+        };
+
+        let (min, max) = match chars.peek() {
+            Some('*') => {
+                chars.next();
+                (0, None)
+            }
+            Some('+') => {
+                chars.next();
+                (1, None)
+            }
+            Some('{') => {
+                chars.next();
+                let range: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                match range.split_once(',') {
+                    Some((min, max)) => (min.trim().parse().unwrap_or(0), max.trim().parse().ok()),
+                    None => {
+                        let count = range.trim().parse().unwrap_or(1);
+                        (count, Some(count))
+                    }
+                }
+            }
+            _ => (1, Some(1)),
+        };
+        tokens.push(Token { atom, min, max });
+    }
+    tokens
+}
+
+fn match_tokens(tokens: &[Token], text: &[char]) -> bool {
+    let Some((token, rest)) = tokens.split_first() else {
+        return text.is_empty();
+    };
+    let mut count = 0;
+    loop {
+        if count >= token.min && match_tokens(rest, &text[count..]) {
+            return true;
+        }
+        if token.max.is_some_and(|max| count >= max) || count >= text.len() || !token.atom.matches(text[count]) {
+            return false;
+        }
+        count += 1;
+    }
+}
+
+/// intent-filter 하나의 data 조건.
+///
+/// Android는 한 필터 안의 `<data>` 요소를 따로 보지 않고 속성별로 모두 합친다.
+/// `<data android:scheme="a"/><data android:host="h"/>`는 `a://h`를 허용한다.
+#[derive(Debug, Default)]
+pub struct FilterData {
+    pub schemes: Vec<String>,
+    /// (host, port). host 없이 선언된 port는 무시된다
+    pub authorities: Vec<(String, Option<String>)>,
+    pub paths: Vec<UriPattern>,
+    pub ssps: Vec<UriPattern>,
+    pub fragments: Vec<UriPattern>,
+    pub mime_types: Vec<String>,
+}
+
+impl FilterData {
+    pub fn from_filter(filter: &IntentFilter) -> Self {
+        let mut data = FilterData::default();
+        for element in &filter.data {
+            push_unique(&mut data.schemes, element.scheme.clone());
+            push_unique(&mut data.mime_types, element.mime_type.clone());
+            if let Some(host) = &element.host {
+                push_unique(&mut data.authorities, Some((host.clone(), element.port.clone())));
+            }
+            for pattern in path_patterns(element) {
+                push_unique(&mut data.paths, Some(pattern));
+            }
+            for pattern in ssp_patterns(element) {
+                push_unique(&mut data.ssps, Some(pattern));
+            }
+            for pattern in fragment_patterns(element) {
+                push_unique(&mut data.fragments, Some(pattern));
+            }
+        }
+        data
+    }
+
+    /// 필터가 받아들이는 구체적인 URI 예시.
+    ///
+    /// scheme이 없으면 다른 속성은 무시되고, host가 없으면 port와 path가 무시된다.
+    /// ssp는 authority/path와 별개로 `scheme:ssp` 형태로 매칭된다.
+    pub fn example_uris(&self) -> Vec<String> {
+        let mut uris = Vec::new();
+        let fragments: Vec<Option<String>> = if self.fragments.is_empty() {
+            vec![None]
+        } else {
+            self.fragments.iter().map(|f| Some(f.example())).collect()
+        };
+
+        for scheme in &self.schemes {
+            let mut bases = Vec::new();
+            for ssp in &self.ssps {
+                bases.push(format!("{}:{}", scheme, ssp.example()));
+            }
+            if self.authorities.is_empty() {
+                if self.ssps.is_empty() {
+                    bases.push(format!("{}://", scheme));
+                }
+            } else {
+                for (host, port) in &self.authorities {
+                    let authority = match port {
+                        Some(port) => format!("{}:{}", example_host(host), port),
+                        None => example_host(host),
+                    };
+                    if self.paths.is_empty() {
+                        bases.push(format!("{}://{}", scheme, authority));
+                    }
+                    for path in &self.paths {
+                        let path = path.example();
+                        let separator = if path.starts_with('/') { "" } else { "/" };
+                        bases.push(format!("{}://{}{}{}", scheme, authority, separator, path));
+                    }
+                }
+            }
+
+            for base in bases {
+                for fragment in &fragments {
+                    let uri = match fragment {
+                        Some(fragment) => format!("{}#{}", base, fragment),
+                        None => base.clone(),
+                    };
+                    if !uris.contains(&uri) {
+                        uris.push(uri);
+                    }
+                }
+            }
+        }
+        uris
+    }
+}
+
+fn push_unique<T: PartialEq>(values: &mut Vec<T>, value: Option<T>) {
+    if let Some(value) = value {
+        if !values.contains(&value) {
+            values.push(value);
+        }
+    }
+}
+
+/// `*.example.com` 같은 와일드카드 host를 실제 host로 바꾼다.
+fn example_host(host: &str) -> String {
+    match host.strip_prefix('*') {
+        Some("") => "example.com".to_string(),
+        Some(rest) => format!("www{}", rest),
+        None => host.to_string(),
+    }
+}
+
+fn patterns(values: [(&Option<String>, PatternKind); 5]) -> Vec<UriPattern> {
+    values.into_iter()
+        .filter_map(|(value, kind)| value.as_deref().map(|v| UriPattern::new(kind, v)))
+        .collect()
+}
+
+pub fn path_patterns(data: &DataElement) -> Vec<UriPattern> {
+    patterns([
+        (&data.path, PatternKind::Literal),
+        (&data.path_prefix, PatternKind::Prefix),
+        (&data.path_pattern, PatternKind::SimpleGlob),
+        (&data.path_advanced_pattern, PatternKind::AdvancedGlob),
+        (&data.path_suffix, PatternKind::Suffix),
+    ])
+}
+
+pub fn ssp_patterns(data: &DataElement) -> Vec<UriPattern> {
+    patterns([
+        (&data.ssp, PatternKind::Literal),
+        (&data.ssp_prefix, PatternKind::Prefix),
+        (&data.ssp_pattern, PatternKind::SimpleGlob),
+        (&data.ssp_advanced_pattern, PatternKind::AdvancedGlob),
+        (&data.ssp_suffix, PatternKind::Suffix),
+    ])
+}
+
+pub fn fragment_patterns(data: &DataElement) -> Vec<UriPattern> {
+    patterns([
+        (&data.fragment, PatternKind::Literal),
+        (&data.fragment_prefix, PatternKind::Prefix),
+        (&data.fragment_pattern, PatternKind::SimpleGlob),
+        (&data.fragment_advanced_pattern, PatternKind::AdvancedGlob),
+        (&data.fragment_suffix, PatternKind::Suffix),
+    ])
+}
+
+/// 컴포넌트의 모든 intent-filter에서 만든 예시 URI (최대 [`MAX_EXAMPLE_URIS`]개)
+pub fn example_uris(component: &Component) -> Vec<String> {
+    let mut uris = Vec::new();
+    for filter in &component.intent_filters {
+        for uri in FilterData::from_filter(filter).example_uris() {
+            if !uris.contains(&uri) {
+                uris.push(uri);
+            }
+        }
+    }
+    if uris.len() > MAX_EXAMPLE_URIS {
+        debug!("{} example URIs for {}; keeping the first {}", uris.len(), component.name, MAX_EXAMPLE_URIS);
+        uris.truncate(MAX_EXAMPLE_URIS);
+    }
+    uris
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use xml::name::OwnedName;
+use xml::reader::{ParserConfig, XmlEvent};
+
+/// 줄/열 위치를 원문 바이트 오프셋으로 바꾸기 위한 줄 시작 위치 목록
+pub struct LineIndex {
+    starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let mut starts = vec![0];
+        starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+        Self { starts }
+    }
+
+    /// 1부터 시작하는 줄/열(문자 단위)을 바이트 오프셋으로 변환한다.
+    pub fn offset(&self, text: &str, line: usize, column: usize) -> Option<usize> {
+        let line_start = *self.starts.get(line.checked_sub(1)?)?;
+        let rest = &text[line_start..];
+        let column_offset = rest.char_indices()
+            .nth(column.saturating_sub(1))
+            .map(|(i, _)| i)
+            .unwrap_or(rest.len());
+        Some(line_start + column_offset)
+    }
+}
+
+/// `start`의 '<'로 시작하는 태그를 닫는 '>' 바로 뒤의 오프셋을 찾는다. 따옴표 안의 '>'는 건너뛴다.
+pub fn tag_end(text: &str, start: usize) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in text[start..].char_indices() {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(start + i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// 원문 조각을 4칸 들여쓰기와 큰따옴표 속성으로 정규화한다.
+///
+/// 조각에는 `android:` 같은 접두사 선언이 없으므로 매니페스트 루트의 네임스페이스를
+/// 선언한 임시 요소로 감싸서 파싱한다. 파싱할 수 없으면 `None`을 반환한다.
+pub fn pretty_print(fragment: &str, namespaces: &BTreeMap<String, String>) -> Option<String> {
+    let mut wrapped = String::from("<aintent-fragment");
+    for (prefix, uri) in namespaces {
+        wrapped.push_str(&format!(" xmlns:{}=\"{}\"", prefix, escape(uri)));
+    }
+    wrapped.push('>');
+    wrapped.push_str(fragment);
+    wrapped.push_str("</aintent-fragment>");
+
+    let reader = ParserConfig::new()
+        .trim_whitespace(true)
+        .ignore_comments(false)
+        .cdata_to_characters(true)
+        .create_reader(wrapped.as_bytes());
+
+    let mut out = String::new();
+    let mut depth = 0usize;
+    let mut pending: Option<String> = None;
+
+    for event in reader {
+        match event.ok()? {
+            XmlEvent::StartElement { name, attributes, .. } => {
+                flush_start(&mut out, &mut pending);
+                depth += 1;
+                if depth == 1 {
+                    continue;
+                }
+                let mut tag = format!("{}<{}", indent(depth - 2), qualified(&name));
+                for attr in attributes {
+                    tag.push_str(&format!(" {}=\"{}\"", qualified(&attr.name), escape(&attr.value)));
+                }
+                pending = Some(tag);
+            }
+            XmlEvent::EndElement { name } => {
+                if depth <= 1 {
+                    break;
+                }
+                match pending.take() {
+                    Some(tag) => out.push_str(&format!("{} />\n", tag)),
+                    None => out.push_str(&format!("{}</{}>\n", indent(depth - 2), qualified(&name))),
+                }
+                depth -= 1;
+            }
+            XmlEvent::Characters(text) => {
+                flush_start(&mut out, &mut pending);
+                out.push_str(&format!("{}{}\n", indent(depth.saturating_sub(1)), escape(&text)));
+            }
+            XmlEvent::Comment(text) => {
+                flush_start(&mut out, &mut pending);
+                out.push_str(&format!("{}<!--{}-->\n", indent(depth.saturating_sub(1)), text));
+            }
+            _ => {}
+        }
+    }
+
+    Some(out.trim_end().to_string())
+}
+
+fn flush_start(out: &mut String, pending: &mut Option<String>) {
+    if let Some(tag) = pending.take() {
+        out.push_str(&tag);
+        out.push_str(">\n");
+    }
+}
+
+fn qualified(name: &OwnedName) -> String {
+    match &name.prefix {
+        Some(prefix) => format!("{}:{}", prefix, name.local_name),
+        None => name.local_name.clone(),
+    }
+}
+
+fn indent(level: usize) -> String {
+    "    ".repeat(level)
+}
+
+/// XML/HTML 텍스트와 속성 값에 쓸 수 있게 `&`, `<`, `>`, `"`를 이스케이프한다.
+pub fn escape(value: &str) -> String {
+    value.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
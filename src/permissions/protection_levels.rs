@@ -28,6 +28,10 @@ lazy_static! {
         m.insert("android.permission.SEND_SMS", "dangerous");
         m.insert("android.permission.READ_EXTERNAL_STORAGE", "dangerous");
         m.insert("android.permission.WRITE_EXTERNAL_STORAGE", "dangerous");
+        m.insert("android.permission.ACCESS_BACKGROUND_LOCATION", "dangerous");
+        m.insert("android.permission.ANSWER_PHONE_CALLS", "dangerous");
+        m.insert("android.permission.ACCEPT_HANDOVER", "dangerous");
+        m.insert("android.permission.GET_ACCOUNTS", "dangerous");
         m.insert("android.permission.READ_MEDIA_IMAGES", "dangerous");
         m.insert("android.permission.READ_MEDIA_VIDEO", "dangerous");
         m.insert("android.permission.READ_MEDIA_AUDIO", "dangerous");
@@ -71,6 +75,14 @@ lazy_static! {
         m.insert("android.permission.BIND_VOICE_INTERACTION", "signature");
         m.insert("android.permission.BIND_VPN_SERVICE", "signature");
         m.insert("android.permission.BIND_WALLPAPER", "signature");
+        m.insert("android.permission.BIND_JOB_SERVICE", "signature");
+        m.insert("android.permission.BIND_CONTROLS", "signature");
+        m.insert("android.permission.BIND_CREDENTIAL_PROVIDER_SERVICE", "signature");
+        m.insert("android.permission.BIND_CARRIER_MESSAGING_SERVICE", "signature");
+        m.insert("android.permission.BIND_CALL_REDIRECTION_SERVICE", "signature|privileged");
+        m.insert("android.permission.BROADCAST_SMS", "signature");
+        m.insert("android.permission.BROADCAST_WAP_PUSH", "signature");
+        m.insert("android.permission.BROADCAST_PACKAGE_REMOVED", "signature");
         m.insert("android.permission.CLEAR_APP_CACHE", "signature");
         m.insert("android.permission.CONTROL_LOCATION_UPDATES", "signature");
         m.insert("android.permission.DELETE_CACHE_FILES", "signature");
@@ -119,11 +131,7 @@ lazy_static! {
         m.insert("android.permission.READ_SYNC_STATS", "signature");
         m.insert("android.permission.READ_USER_DICTIONARY", "signature");
         m.insert("android.permission.REBOOT", "signature");
-        m.insert("android.permission.RECEIVE_BOOT_COMPLETED", "signature");
         m.insert("android.permission.RECEIVE_DATA_ACTIVITY_CHANGE", "signature");
-        m.insert("android.permission.RECEIVE_MMS", "signature");
-        m.insert("android.permission.RECEIVE_SMS", "signature");
-        m.insert("android.permission.RECEIVE_WAP_PUSH", "signature");
         m.insert("android.permission.RECOVERY", "signature");
         m.insert("android.permission.SEND_RESPOND_VIA_MESSAGE", "signature");
         m.insert("android.permission.SERIAL_PORT", "signature");
@@ -146,7 +154,6 @@ lazy_static! {
         m.insert("android.permission.UPDATE_DEVICE_STATS", "signature");
         m.insert("android.permission.UPDATE_LOCK", "signature");
         m.insert("android.permission.USE_CREDENTIALS", "signature");
-        m.insert("android.permission.USE_SIP", "signature");
         m.insert("android.permission.WRITE_APN_SETTINGS", "signature");
         m.insert("android.permission.WRITE_GSERVICES", "signature");
         m.insert("android.permission.WRITE_SECURE_SETTINGS", "signature");
@@ -211,12 +218,55 @@ lazy_static! {
         m.insert("android.permission.ACCESS_NETWORK_STATE", "normal");
         m.insert("android.permission.ACCESS_WIFI_STATE", "normal");
         m.insert("android.permission.VIBRATE", "normal");
+        m.insert("android.permission.INTERNET", "normal");
+        m.insert("android.permission.FOREGROUND_SERVICE", "normal");
+        m.insert("android.permission.NFC", "normal");
+        m.insert("android.permission.SET_ALARM", "normal");
+        m.insert("android.permission.KILL_BACKGROUND_PROCESSES", "normal");
+        m.insert("android.permission.USE_FINGERPRINT", "normal");
+        m.insert("android.permission.USE_BIOMETRIC", "normal");
+        m.insert("android.permission.RECEIVE_BOOT_COMPLETED", "normal");
+        m.insert("android.permission.ACCESS_NOTIFICATION_POLICY", "normal");
 
         m
     };
 }
 
-#[allow(dead_code)]
+/// 플랫폼 권한의 보호 수준. 목록에 없으면 `unknown`
+///
+/// 앱이 정의한 권한은 [`Component::protection_level`](crate::manifest::Component::protection_level)로
+/// 매니페스트의 `<permission>` 선언을 먼저 본다.
 pub fn get_permission_protection_level(permission: &str) -> &'static str {
-    PERMISSION_PROTECTION_LEVELS.get(permission).unwrap_or(&"normal")
-} 
\ No newline at end of file
+    PERMISSION_PROTECTION_LEVELS.get(permission).unwrap_or(&"unknown")
+}
+
+/// `<permission android:protectionLevel>` 값을 표의 이름(`normal`, `dangerous`, `signature`,
+/// `signature|privileged`)으로 바꾼다. 속성이 없으면 `normal`
+pub fn normalize_protection_level(value: Option<&str>) -> &'static str {
+    let Some(value) = value.map(str::trim) else {
+        return "normal";
+    };
+    // 정수 값 (병합된 매니페스트 등): 하위 4비트가 기본 수준이다
+    let number = match value.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    };
+    if let Some(number) = number {
+        return match number & 0xf {
+            0 => "normal",
+            1 => "dangerous",
+            3 => "signature|privileged",
+            _ => "signature",
+        };
+    }
+    let flags: Vec<&str> = value.split('|').map(str::trim).collect();
+    if flags.iter().any(|f| matches!(*f, "signatureOrSystem" | "privileged" | "system")) {
+        "signature|privileged"
+    } else if flags.iter().any(|f| matches!(*f, "signature" | "internal")) {
+        "signature"
+    } else if flags.contains(&"dangerous") {
+        "dangerous"
+    } else {
+        "normal"
+    }
+}
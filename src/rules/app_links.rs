@@ -0,0 +1,273 @@
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use tracing::debug;
+use crate::manifest::component::IntentFilter;
+use crate::manifest::Component;
+use crate::manifest::uri::FilterData;
+use crate::rules::{Finding, Rule, Severity};
+
+pub const UNVERIFIED_APP_LINK: Rule = Rule {
+    id: "AINT005",
+    name: "UnverifiedAppLink",
+    description: "App Link host fails Digital Asset Links verification, so links open in a disambiguation dialog where other apps that claim the host can intercept them.",
+    severity: Severity::Warning,
+};
+
+pub const WEB_LINK_WITHOUT_AUTO_VERIFY: Rule = Rule {
+    id: "AINT006",
+    name: "WebLinkWithoutAutoVerify",
+    description: "Activity handles http/https links without android:autoVerify, so any app can register the same hosts and be offered the links.",
+    severity: Severity::Note,
+};
+
+pub const RULES: &[Rule] = &[
+    UNVERIFIED_APP_LINK,
+    WEB_LINK_WITHOUT_AUTO_VERIFY,
+];
+
+const VIEW_ACTION: &str = "android.intent.action.VIEW";
+const BROWSABLE_CATEGORY: &str = "android.intent.category.BROWSABLE";
+const DEFAULT_CATEGORY: &str = "android.intent.category.DEFAULT";
+const HANDLE_ALL_URLS: &str = "delegate_permission/common.handle_all_urls";
+
+/// 매니페스트만으로 판단할 수 있는 검사: autoVerify가 무시되는 필터와 autoVerify 없는 웹 링크
+pub fn check(component: &Component) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !component.is_effectively_exported() || component.component_type != "activity" {
+        return findings;
+    }
+
+    for filter in &component.intent_filters {
+        let data = FilterData::from_filter(filter);
+        let web_hosts = web_hosts(&data);
+        if web_hosts.is_empty() || !filter.categories.iter().any(|c| c == BROWSABLE_CATEGORY) {
+            continue;
+        }
+
+        if !filter.auto_verify {
+            findings.push(filter_finding(
+                &WEB_LINK_WITHOUT_AUTO_VERIFY,
+                component,
+                filter,
+                format!(
+                    "{} handles web links to {} without android:autoVerify; other apps can register the same hosts",
+                    component.name,
+                    web_hosts.join(", ")
+                ),
+            ));
+            continue;
+        }
+
+        if let Some(problem) = auto_verify_problem(filter, &data) {
+            findings.push(filter_finding(
+                &UNVERIFIED_APP_LINK,
+                component,
+                filter,
+                format!(
+                    "android:autoVerify on {} has no effect ({}); links to {} open in a disambiguation dialog",
+                    component.name,
+                    problem,
+                    web_hosts.join(", ")
+                ),
+            ));
+        }
+    }
+    findings
+}
+
+/// `assetlinks.json` 미러로 autoVerify 필터의 host를 검증한다 (Android 12 이상처럼 host별로 판단).
+pub fn check_verification(component: &Component, mirror: &AssetLinksMirror) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !component.is_effectively_exported() || component.component_type != "activity" {
+        return findings;
+    }
+
+    for filter in component.intent_filters.iter().filter(|f| f.auto_verify) {
+        let data = FilterData::from_filter(filter);
+        // 필터 자체가 검증 대상이 아니면 check()에서 이미 보고했다
+        if auto_verify_problem(filter, &data).is_some() {
+            continue;
+        }
+        for host in web_hosts(&data) {
+            let verification = mirror.verify(&host, &component.package);
+            debug!("App Link {} for {}: {:?}", host, component.package, verification);
+            if let Some(reason) = verification.failure() {
+                findings.push(filter_finding(
+                    &UNVERIFIED_APP_LINK,
+                    component,
+                    filter,
+                    format!(
+                        "App Link host {} of {} is not verified: {}; links open in a disambiguation dialog where other apps can claim them",
+                        host, component.name, reason
+                    ),
+                ));
+            }
+        }
+    }
+    findings
+}
+
+/// http/https scheme에 선언된 host
+fn web_hosts(data: &FilterData) -> Vec<String> {
+    if !data.schemes.iter().any(|s| s == "http" || s == "https") {
+        return Vec::new();
+    }
+    data.authorities.iter().map(|(host, _)| host.clone()).collect()
+}
+
+/// autoVerify가 적용되지 않는 이유
+fn auto_verify_problem(filter: &IntentFilter, data: &FilterData) -> Option<String> {
+    let mut missing = Vec::new();
+    if !filter.actions.iter().any(|a| a == VIEW_ACTION) {
+        missing.push("VIEW action");
+    }
+    if !filter.categories.iter().any(|c| c == DEFAULT_CATEGORY) {
+        missing.push("DEFAULT category");
+    }
+    if !missing.is_empty() {
+        return Some(format!("missing {}", missing.join(" and ")));
+    }
+    let other_schemes: Vec<&str> = data.schemes.iter()
+        .filter(|s| *s != "http" && *s != "https")
+        .map(String::as_str)
+        .collect();
+    if !other_schemes.is_empty() {
+        return Some(format!("filter also declares non-web schemes {}", other_schemes.join(", ")));
+    }
+    None
+}
+
+fn filter_finding(rule: &Rule, component: &Component, filter: &IntentFilter, message: String) -> Finding {
+    let mut finding = Finding::new(rule, rule.severity, component, message);
+    if filter.line > 0 {
+        finding.location.line = filter.line;
+        finding.location.column = (filter.column > 0).then_some(filter.column);
+    }
+    finding
+}
+
+/// 로컬에 저장한 `/.well-known/assetlinks.json` 사본
+///
+/// 파일 하나를 지정하면 모든 host에 사용하고, 디렉토리면 host별로
+/// `<host>/.well-known/assetlinks.json`, `<host>/assetlinks.json`, `<host>.json` 순으로 찾는다.
+#[derive(Debug)]
+pub struct AssetLinksMirror {
+    path: PathBuf,
+    /// 스캔한 앱의 서명 인증서 SHA-256 지문. 비어 있으면 패키지 이름만 확인한다
+    fingerprints: Vec<String>,
+}
+
+/// host 하나의 검증 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum HostVerification {
+    Verified,
+    MissingFile,
+    Invalid(String),
+    PackageNotListed,
+    FingerprintMismatch(Vec<String>),
+}
+
+impl HostVerification {
+    /// 검증에 실패한 이유
+    pub fn failure(&self) -> Option<String> {
+        match self {
+            HostVerification::Verified => None,
+            HostVerification::MissingFile => Some("no assetlinks.json in the mirror".to_string()),
+            HostVerification::Invalid(e) => Some(format!("assetlinks.json is invalid ({})", e)),
+            HostVerification::PackageNotListed => Some("assetlinks.json does not delegate handle_all_urls to the package".to_string()),
+            HostVerification::FingerprintMismatch(listed) => Some(format!(
+                "signing certificate is not listed (assetlinks.json has {})",
+                if listed.is_empty() { "no fingerprints".to_string() } else { listed.join(", ") }
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Statement {
+    #[serde(default)]
+    relation: Vec<String>,
+    target: Option<Target>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Target {
+    namespace: String,
+    package_name: Option<String>,
+    #[serde(default)]
+    sha256_cert_fingerprints: Vec<String>,
+}
+
+impl AssetLinksMirror {
+    pub fn new(path: PathBuf, fingerprints: &[String]) -> Self {
+        Self {
+            path,
+            fingerprints: fingerprints.iter().map(|f| normalize_fingerprint(f)).collect(),
+        }
+    }
+
+    pub fn verify(&self, host: &str, package: &str) -> HostVerification {
+        // `*.example.com`은 example.com의 assetlinks.json으로 검증된다
+        let host = host.strip_prefix("*.").unwrap_or(host);
+        let Some(file) = self.file_for_host(host) else {
+            return HostVerification::MissingFile;
+        };
+        let statements: Vec<Statement> = match std::fs::read_to_string(&file)
+            .map_err(|e| e.to_string())
+            .and_then(|content| serde_json::from_str(&content).map_err(|e| e.to_string()))
+        {
+            Ok(statements) => statements,
+            Err(e) => return HostVerification::Invalid(e),
+        };
+
+        let targets: Vec<&Target> = statements.iter()
+            .filter(|s| s.relation.iter().any(|r| r == HANDLE_ALL_URLS))
+            .filter_map(|s| s.target.as_ref())
+            .filter(|t| t.namespace == "android_app" && t.package_name.as_deref() == Some(package))
+            .collect();
+        if targets.is_empty() {
+            return HostVerification::PackageNotListed;
+        }
+        if self.fingerprints.is_empty() {
+            return HostVerification::Verified;
+        }
+
+        let listed: Vec<String> = targets.iter()
+            .flat_map(|t| t.sha256_cert_fingerprints.iter())
+            .map(|f| normalize_fingerprint(f))
+            .collect();
+        if listed.iter().any(|f| self.fingerprints.contains(f)) {
+            HostVerification::Verified
+        } else {
+            HostVerification::FingerprintMismatch(listed)
+        }
+    }
+
+    fn file_for_host(&self, host: &str) -> Option<PathBuf> {
+        if self.path.is_file() {
+            return Some(self.path.clone());
+        }
+        let candidates = [
+            self.path.join(host).join(".well-known").join("assetlinks.json"),
+            self.path.join(host).join("assetlinks.json"),
+            self.path.join(format!("{}.json", host)),
+        ];
+        candidates.into_iter().find(|p| p.is_file())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// `aa:bb:..`, `AABB..` 형식 모두 `AA:BB:..`로 맞춘다.
+fn normalize_fingerprint(fingerprint: &str) -> String {
+    let hex: Vec<char> = fingerprint.chars()
+        .filter(|c| c.is_ascii_hexdigit())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    hex.chunks(2)
+        .map(|pair| pair.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join(":")
+}
@@ -0,0 +1,219 @@
+use std::collections::BTreeSet;
+use tracing::info;
+use crate::manifest::Component;
+use crate::manifest::uri::{FilterData, PatternKind, UriPattern};
+use crate::rules::{Finding, Rule, Severity};
+
+pub const LINK_COLLISION: Rule = Rule {
+    id: "AINT007",
+    name: "DeepLinkCollision",
+    description: "Another scanned app registers an overlapping scheme, host, path or MIME type, so it can be offered or chosen for the same links.",
+    severity: Severity::Warning,
+};
+
+pub const RULES: &[Rule] = &[LINK_COLLISION];
+
+const BROWSABLE_CATEGORY: &str = "android.intent.category.BROWSABLE";
+
+/// intent-filter 하나가 받는 링크 조건 하나 (scheme × host × path, 또는 MIME 타입)
+#[derive(Debug, Clone)]
+struct LinkClaim<'a> {
+    component: &'a Component,
+    actions: &'a [String],
+    scheme: Option<String>,
+    host: Option<String>,
+    path: Option<UriPattern>,
+    mime_type: Option<String>,
+    browsable: bool,
+    line: usize,
+    column: usize,
+}
+
+impl LinkClaim<'_> {
+    fn describe(&self) -> String {
+        let Some(scheme) = &self.scheme else {
+            return format!("type {}", self.mime_type.as_deref().unwrap_or("*/*"));
+        };
+        let mut link = format!("{}://{}", scheme, self.host.as_deref().unwrap_or("*"));
+        if let Some(path) = &self.path {
+            link.push_str(&describe_path(path));
+        }
+        if let Some(mime_type) = &self.mime_type {
+            link.push_str(&format!(" ({})", mime_type));
+        }
+        link
+    }
+
+    fn overlaps(&self, other: &LinkClaim) -> bool {
+        self.actions.iter().any(|a| other.actions.contains(a))
+            && self.scheme == other.scheme
+            && hosts_overlap(self.host.as_deref(), other.host.as_deref())
+            && paths_overlap(self.path.as_ref(), other.path.as_ref())
+            && mime_types_overlap(self.mime_type.as_deref(), other.mime_type.as_deref())
+    }
+}
+
+/// 스캔한 모든 앱의 exported activity를 비교해 다른 패키지와 겹치는 링크 조건을 보고한다.
+///
+/// 겹치는 링크 조건마다 컴포넌트 하나에 발견 사항 하나를 만든다. 어느 한쪽이라도
+/// BROWSABLE이면 브라우저의 링크로 가로챌 수 있으므로 warning, 아니면 note다.
+pub fn check(components: &[Component]) -> Vec<Finding> {
+    let claims: Vec<LinkClaim> = components.iter()
+        .filter(|c| c.is_effectively_exported() && c.component_type == "activity")
+        .flat_map(link_claims)
+        .collect();
+
+    let mut findings = Vec::new();
+    let mut collided_links = BTreeSet::new();
+    for claim in &claims {
+        let others: Vec<&LinkClaim> = claims.iter()
+            .filter(|other| other.component.package != claim.component.package)
+            .filter(|other| claim.overlaps(other))
+            .collect();
+        if others.is_empty() {
+            continue;
+        }
+
+        let browsable = claim.browsable || others.iter().any(|o| o.browsable);
+        let mut claimed_by: Vec<String> = others.iter()
+            .map(|o| format!("{} in {} ({})", o.describe(), o.component.package, o.component.class_name))
+            .collect();
+        claimed_by.sort();
+        claimed_by.dedup();
+        let link = claim.describe();
+        collided_links.insert(link.clone());
+
+        let mut finding = Finding::new(
+            &LINK_COLLISION,
+            if browsable { Severity::Warning } else { Severity::Note },
+            claim.component,
+            format!(
+                "{} of {} is also claimed by {}{}",
+                link,
+                claim.component.name,
+                claimed_by.join(", "),
+                if browsable { "; reachable from a browser" } else { "" }
+            ),
+        );
+        if claim.line > 0 {
+            finding.location.line = claim.line;
+            finding.location.column = (claim.column > 0).then_some(claim.column);
+        }
+        if !findings.iter().any(|f: &Finding| f.component == finding.component && f.message == finding.message) {
+            findings.push(finding);
+        }
+    }
+
+    if !collided_links.is_empty() {
+        info!("Found {} links claimed by more than one package", collided_links.len());
+    }
+    findings
+}
+
+/// Android가 한 필터의 `<data>`를 합치는 방식대로 조건을 펼친다.
+fn link_claims(component: &Component) -> Vec<LinkClaim<'_>> {
+    let mut claims = Vec::new();
+    for filter in component.intent_filters.iter().filter(|f| !f.actions.is_empty()) {
+        let data = FilterData::from_filter(filter);
+        let browsable = filter.categories.iter().any(|c| c == BROWSABLE_CATEGORY);
+        let base = LinkClaim {
+            component,
+            actions: &filter.actions,
+            scheme: None,
+            host: None,
+            path: None,
+            mime_type: None,
+            browsable,
+            line: filter.line,
+            column: filter.column,
+        };
+
+        let mime_types: Vec<Option<String>> = if data.mime_types.is_empty() {
+            vec![None]
+        } else {
+            data.mime_types.iter().cloned().map(Some).collect()
+        };
+        if data.schemes.is_empty() {
+            claims.extend(data.mime_types.iter().map(|mime_type| LinkClaim {
+                mime_type: Some(mime_type.clone()),
+                ..base.clone()
+            }));
+            continue;
+        }
+
+        // host가 없으면 path는 무시된다
+        let hosts: Vec<Option<String>> = if data.authorities.is_empty() {
+            vec![None]
+        } else {
+            data.authorities.iter().map(|(host, _)| Some(host.clone())).collect()
+        };
+        let paths: Vec<Option<UriPattern>> = if data.paths.is_empty() || data.authorities.is_empty() {
+            vec![None]
+        } else {
+            data.paths.iter().cloned().map(Some).collect()
+        };
+        for scheme in &data.schemes {
+            for host in &hosts {
+                for path in &paths {
+                    for mime_type in &mime_types {
+                        claims.push(LinkClaim {
+                            scheme: Some(scheme.clone()),
+                            host: host.clone(),
+                            path: path.clone(),
+                            mime_type: mime_type.clone(),
+                            ..base.clone()
+                        });
+                    }
+                }
+            }
+        }
+    }
+    claims
+}
+
+fn describe_path(path: &UriPattern) -> String {
+    match path.kind {
+        PatternKind::Literal => path.value.clone(),
+        PatternKind::Prefix => format!("{}*", path.value),
+        PatternKind::Suffix => format!("*{}", path.value),
+        PatternKind::SimpleGlob | PatternKind::AdvancedGlob => format!("{} (pattern)", path.value),
+    }
+}
+
+/// host가 없는 조건은 모든 host를 받는다. `*.example.com`은 하위 도메인을 모두 받는다.
+fn hosts_overlap(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (None, _) | (_, None) => true,
+        (Some(a), Some(b)) => host_matches(a, b) || host_matches(b, a),
+    }
+}
+
+fn host_matches(pattern: &str, host: &str) -> bool {
+    match pattern.strip_prefix('*') {
+        // Android는 `*` 뒤의 `.example.com`을 그대로 접미사로 비교한다
+        Some(suffix) => host.to_ascii_lowercase().ends_with(&suffix.to_ascii_lowercase()),
+        None => pattern.eq_ignore_ascii_case(host),
+    }
+}
+
+/// 패턴끼리의 교집합은 근사한다: 한쪽의 예시가 다른 쪽에 맞으면 겹친다고 본다.
+fn paths_overlap(a: Option<&UriPattern>, b: Option<&UriPattern>) -> bool {
+    match (a, b) {
+        (None, _) | (_, None) => true,
+        (Some(a), Some(b)) => a.matches(&b.example()) || b.matches(&a.example()),
+    }
+}
+
+fn mime_types_overlap(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => {
+            let (a_type, a_sub) = a.split_once('/').unwrap_or((a, "*"));
+            let (b_type, b_sub) = b.split_once('/').unwrap_or((b, "*"));
+            (a_type == "*" || b_type == "*" || a_type == b_type)
+                && (a_sub == "*" || b_sub == "*" || a_sub == b_sub)
+        }
+        // 타입이 있는 intent는 타입을 선언한 필터에만 맞는다
+        _ => false,
+    }
+}
@@ -0,0 +1,62 @@
+use crate::manifest::component::FileProviderPath;
+use crate::manifest::Component;
+use crate::rules::{provider, Finding, Rule, Severity};
+use crate::utils::provider::file_provider_uri;
+
+pub const BROAD_FILE_PROVIDER_PATHS: Rule = Rule {
+    id: "AINT012",
+    name: "BroadFileProviderPaths",
+    description: "FileProvider paths XML shares the whole file system, the app's private directories or all of external storage, so any URI the app grants (or leaks through intent redirection) can reach unrelated files.",
+    severity: Severity::Error,
+};
+
+pub const RULES: &[Rule] = &[BROAD_FILE_PROVIDER_PATHS];
+
+/// FileProvider 경로 항목 중 공유 범위가 넓은 것을 찾는다.
+pub fn check(component: &Component) -> Vec<Finding> {
+    let Some(info) = &component.provider else {
+        return Vec::new();
+    };
+    if info.file_paths.is_empty() || !provider::reachable(component) {
+        return Vec::new();
+    }
+
+    let mut findings = Vec::new();
+    for entry in &info.file_paths {
+        let Some((severity, scope)) = classify(entry, &component.package) else {
+            continue;
+        };
+        let mut message = format!(
+            "{} shares {} through <{} name=\"{}\" path=\"{}\">",
+            component.name, scope, entry.kind, entry.name, entry.path
+        );
+        if let Some(uri) = info.authorities.first().and_then(|a| file_provider_uri(a, entry, &component.package)) {
+            message.push_str(&format!("; example: {}", uri));
+        }
+        let mut finding = Finding::new(&BROAD_FILE_PROVIDER_PATHS, severity, component, message);
+        if let Some(resource) = &info.file_paths_resource {
+            finding.location.path = resource.clone();
+            finding.location.line = entry.line.max(1);
+            finding.location.column = None;
+        }
+        findings.push(finding);
+    }
+    findings
+}
+
+/// 넓은 항목이면 심각도와 공유 범위 설명
+fn classify(entry: &FileProviderPath, package: &str) -> Option<(Severity, String)> {
+    let shared_dir = entry.shared_dir(package)?;
+    if entry.kind == "root-path" {
+        return Some((Severity::Error, format!("the whole file system from {}", shared_dir)));
+    }
+    if entry.path.split('/').any(|segment| segment == "..") {
+        return Some((Severity::Error, format!("{} outside its {} directory", shared_dir, entry.kind)));
+    }
+    let broad = matches!(entry.path.trim_matches('/'), "" | ".");
+    match entry.kind.as_str() {
+        "external-path" if broad => Some((Severity::Warning, "all of external storage (/sdcard)".to_string())),
+        "files-path" | "cache-path" if broad => Some((Severity::Warning, format!("every file in {}", shared_dir))),
+        _ => None,
+    }
+}
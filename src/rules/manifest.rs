@@ -0,0 +1,192 @@
+use crate::manifest::Component;
+use crate::rules::{Finding, Rule, Severity};
+use crate::utils::guards::PermissionGuard;
+
+pub const EXPORTED_WITHOUT_PERMISSION: Rule = Rule {
+    id: "AINT001",
+    name: "ExportedWithoutPermission",
+    description: "Exported component is not protected by any permission and can be invoked by every app on the device.",
+    severity: Severity::Warning,
+};
+
+pub const WEAK_PERMISSION: Rule = Rule {
+    id: "AINT002",
+    name: "WeakPermissionProtection",
+    description: "Exported component is protected only by a normal or dangerous permission, which any app can request.",
+    severity: Severity::Warning,
+};
+
+pub const BROWSABLE_ENTRY_POINT: Rule = Rule {
+    id: "AINT003",
+    name: "BrowsableEntryPoint",
+    description: "Activity declares the BROWSABLE category and can be launched from links in a web browser.",
+    severity: Severity::Note,
+};
+
+pub const SHARED_USER_ID: Rule = Rule {
+    id: "AINT004",
+    name: "SharedUserId",
+    description: "Component runs in an app that declares android:sharedUserId and shares its identity with other apps.",
+    severity: Severity::Note,
+};
+
+pub const RULES: &[Rule] = &[
+    EXPORTED_WITHOUT_PERMISSION,
+    WEAK_PERMISSION,
+    BROWSABLE_ENTRY_POINT,
+    SHARED_USER_ID,
+];
+
+pub fn check(component: &Component) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    if component.is_effectively_exported() {
+        check_permissions(component, &mut findings);
+
+        if component.component_type == "activity"
+            && component.categories.contains("android.intent.category.BROWSABLE") {
+            let schemes: Vec<&str> = component.data_schemes.iter().map(String::as_str).collect();
+            findings.push(Finding::new(
+                &BROWSABLE_ENTRY_POINT,
+                BROWSABLE_ENTRY_POINT.severity,
+                component,
+                format!(
+                    "{} can be opened from a browser (schemes: {})",
+                    component.name,
+                    if schemes.is_empty() { "-".to_string() } else { schemes.join(", ") }
+                ),
+            ));
+        }
+    }
+
+    if let Some(shared_user_id) = &component.shared_user_id {
+        findings.push(Finding::new(
+            &SHARED_USER_ID,
+            SHARED_USER_ID.severity,
+            component,
+            format!("{} runs with sharedUserId {}", component.name, shared_user_id),
+        ));
+    }
+
+    findings
+}
+
+/// 진입점 코드가 호출자를 확인하면 AINT001/AINT002를 note로 낮추고 확인 위치를 덧붙인다.
+pub fn apply_guards(findings: &mut [Finding], guards: &[PermissionGuard]) {
+    if guards.is_empty() {
+        return;
+    }
+    let checks = guards.iter().map(PermissionGuard::describe).collect::<Vec<_>>().join(", ");
+    for finding in findings.iter_mut()
+        .filter(|f| f.rule_id == EXPORTED_WITHOUT_PERMISSION.id || f.rule_id == WEAK_PERMISSION.id) {
+        finding.severity = Severity::Note;
+        finding.message.push_str(&format!("; checked in code: {}", checks));
+    }
+}
+
+fn check_permissions(component: &Component, findings: &mut Vec<Finding>) {
+    // provider는 readPermission/writePermission이 android:permission보다 우선한다
+    let read_permission = component.provider.as_ref().and_then(|p| p.read_permission.as_ref());
+    let write_permission = component.provider.as_ref().and_then(|p| p.write_permission.as_ref());
+    let open_read = component.permissions.is_empty() && read_permission.is_none();
+    let open_write = component.permissions.is_empty() && write_permission.is_none();
+    if open_read && open_write {
+        let mut message = format!("Exported {} {} does not require any permission", component.component_type, component.name);
+        // 플래그 없이 등록한 receiver는 Android 14 미만에서 exported다
+        if component.dynamic.as_ref().is_some_and(|d| d.export_flag.is_none()) {
+            message.push_str("; registered without RECEIVER_NOT_EXPORTED");
+        }
+        if !component.exported && component.is_effectively_exported() {
+            let target_sdk = match (component.target_sdk, component.component_type.as_str()) {
+                (None, _) => "targetSdk unknown",
+                (Some(_), "provider") => "targetSdk 16 or lower",
+                (Some(_), _) => "targetSdk below 31",
+            };
+            let cause = if component.component_type == "provider" { "by default" } else { "implicitly by its intent-filter" };
+            message.push_str(&format!("; exported {} (no android:exported, {})", cause, target_sdk));
+        }
+        findings.push(Finding::new(
+            &EXPORTED_WITHOUT_PERMISSION,
+            EXPORTED_WITHOUT_PERMISSION.severity,
+            component,
+            message,
+        ));
+        return;
+    }
+    if open_read || open_write {
+        findings.push(Finding::new(
+            &EXPORTED_WITHOUT_PERMISSION,
+            EXPORTED_WITHOUT_PERMISSION.severity,
+            component,
+            format!(
+                "Exported provider {} does not require any permission to {}",
+                component.name,
+                if open_read { "read" } else { "write" }
+            ),
+        ));
+    }
+
+    let mut permissions: Vec<&String> = component.permissions.iter()
+        .chain(read_permission)
+        .chain(write_permission)
+        .collect();
+    permissions.sort();
+    permissions.dedup();
+    for permission in permissions {
+        let Some((severity, level)) = weak_permission(component, permission) else {
+            continue;
+        };
+        findings.push(Finding::new(
+            &WEAK_PERMISSION,
+            severity,
+            component,
+            format!(
+                "Exported {} {} is protected only by {} ({})",
+                component.component_type,
+                component.name,
+                permission,
+                level
+            ),
+        ));
+    }
+
+    // <path-permission>은 해당 경로에서 provider 권한 대신 쓰인다
+    for path_permission in component.provider.iter().flat_map(|p| &p.path_permissions) {
+        let mut permissions: Vec<&String> = path_permission.permission.iter()
+            .chain(&path_permission.read_permission)
+            .chain(&path_permission.write_permission)
+            .collect();
+        permissions.dedup();
+        for permission in permissions {
+            let Some((severity, level)) = weak_permission(component, permission) else {
+                continue;
+            };
+            findings.push(Finding::new(
+                &WEAK_PERMISSION,
+                severity,
+                component,
+                format!(
+                    "{}=\"{}\" of provider {} is protected only by {} ({})",
+                    path_permission.path.attribute,
+                    path_permission.path.value,
+                    component.name,
+                    permission,
+                    level
+                ),
+            ));
+        }
+    }
+}
+
+/// 다른 앱이 요청만 하면 얻는 권한이면 심각도와 보호 수준 설명.
+///
+/// JSON/텍스트 출력과 같은 분류(`Component::protection_level`)를 쓴다. 매니페스트에 선언되지 않았고
+/// 플랫폼 권한 표에도 없는 권한은 보호 수준을 알 수 없으므로 note로 알린다.
+fn weak_permission(component: &Component, permission: &str) -> Option<(Severity, &'static str)> {
+    match component.protection_level(permission) {
+        "normal" => Some((Severity::Warning, "normal")),
+        "dangerous" => Some((Severity::Note, "dangerous")),
+        "unknown" => Some((Severity::Note, "unknown; not declared in the scanned manifests or a known platform permission")),
+        _ => None,
+    }
+}
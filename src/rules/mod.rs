@@ -0,0 +1,113 @@
+pub mod app_links;
+pub mod collisions;
+pub mod file_provider;
+pub mod manifest;
+pub mod pending_intent;
+pub mod provider;
+pub mod redirection;
+pub mod task_hijacking;
+pub mod webview;
+
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::manifest::Component;
+
+/// 발견 사항의 심각도 (SARIF level과 동일한 이름)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Note,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Note => "note",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+/// 규칙 메타데이터
+#[derive(Debug)]
+pub struct Rule {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub severity: Severity,
+}
+
+/// 파일 위치 (줄/열은 1부터 시작)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: Option<usize>,
+}
+
+/// 규칙이 컴포넌트에서 찾은 문제 하나
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Finding {
+    pub rule_id: String,
+    pub severity: Severity,
+    pub message: String,
+    pub component: String,
+    pub location: Location,
+}
+
+impl Finding {
+    pub fn new(rule: &Rule, severity: Severity, component: &Component, message: String) -> Self {
+        Self {
+            rule_id: rule.id.to_string(),
+            severity,
+            message,
+            component: component.name.clone(),
+            location: Location {
+                path: component.manifest_path.clone(),
+                line: component.manifest_line.max(1),
+                column: (component.manifest_column > 0).then_some(component.manifest_column),
+            },
+        }
+    }
+
+    /// 컴포넌트 선언과 관계없는 소스 코드의 발견 사항. `class_name`은 코드가 있는 클래스다.
+    pub fn in_source(rule: &Rule, severity: Severity, class_name: String, path: &Path, line: usize, message: String) -> Self {
+        Self {
+            rule_id: rule.id.to_string(),
+            severity,
+            message,
+            component: class_name,
+            location: Location {
+                path: path.to_path_buf(),
+                line,
+                column: None,
+            },
+        }
+    }
+}
+
+/// 알려진 모든 규칙
+pub fn all_rules() -> Vec<&'static Rule> {
+    manifest::RULES.iter()
+        .chain(app_links::RULES)
+        .chain(collisions::RULES)
+        .chain(redirection::RULES)
+        .chain(webview::RULES)
+        .chain(provider::RULES)
+        .chain(file_provider::RULES)
+        .chain(pending_intent::RULES)
+        .chain(task_hijacking::RULES)
+        .collect()
+}
+
+/// 컴포넌트 하나에 모든 규칙을 적용한다.
+pub fn check_component(component: &Component) -> Vec<Finding> {
+    let mut findings = manifest::check(component);
+    findings.extend(app_links::check(component));
+    findings.extend(file_provider::check(component));
+    findings.extend(task_hijacking::check(component));
+    findings
+}
@@ -0,0 +1,315 @@
+use std::path::{Path, PathBuf};
+use anyhow::Result;
+use tracing::warn;
+use tree_sitter::Node;
+use crate::rules::{Finding, Rule, Severity};
+use crate::utils::dataflow::{named_children, node_text, parse};
+
+pub const IMPLICIT_PENDING_INTENT: Rule = Rule {
+    id: "AINT013",
+    name: "ImplicitPendingIntent",
+    description: "PendingIntent wraps an implicit intent, so whichever app resolves it runs with the creating app's identity and permissions; if it is also mutable, the receiver can fill in the component, data and URI grant flags.",
+    severity: Severity::Error,
+};
+
+pub const MUTABLE_PENDING_INTENT: Rule = Rule {
+    id: "AINT014",
+    name: "MutablePendingIntent",
+    description: "PendingIntent is created with FLAG_MUTABLE or without a mutability flag (mutable before Android 12), so apps that receive it can change unfilled fields of its intent.",
+    severity: Severity::Warning,
+};
+
+pub const RULES: &[Rule] = &[IMPLICIT_PENDING_INTENT, MUTABLE_PENDING_INTENT];
+
+const FACTORIES: &[&str] = &["getActivity", "getActivities", "getService", "getForegroundService", "getBroadcast"];
+
+/// 대상 컴포넌트나 패키지를 정하는 Intent 메서드
+const EXPLICIT_SETTERS: &[&str] = &["setClass", "setClassName", "setComponent", "setPackage"];
+
+/// 대상을 바꾸지 않고 같은 Intent를 돌려주는 메서드
+const BUILDERS: &[&str] = &[
+    "setAction",
+    "setData",
+    "setDataAndType",
+    "setType",
+    "addCategory",
+    "addFlags",
+    "setFlags",
+    "putExtra",
+    "putExtras",
+];
+
+const MAX_CODE_LENGTH: usize = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    Explicit,
+    Implicit,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mutability {
+    Mutable,
+    Immutable,
+    /// 플래그가 없음: Android 12 미만에서는 mutable
+    Unspecified,
+    Unknown,
+}
+
+/// 소스 파일에서 `PendingIntent.get*()` 호출을 검사한다.
+pub fn check_sources(files: &[PathBuf]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    for file in files {
+        match check_file(file) {
+            Ok(file_findings) => findings.extend(file_findings),
+            Err(e) => warn!("Failed to check PendingIntents in {}: {}", file.display(), e),
+        }
+    }
+    findings
+}
+
+fn check_file(path: &Path) -> Result<Vec<Finding>> {
+    let source = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read source file: {}", e))?;
+    if !source.contains("PendingIntent") {
+        return Ok(Vec::new());
+    }
+    let tree = parse(&source)?;
+    let root = tree.root_node();
+    let package = named_children(root).into_iter()
+        .find(|n| n.kind() == "package_declaration")
+        .and_then(|n| n.named_child(0))
+        .map(|n| node_text(n, &source).to_string());
+
+    let mut findings = Vec::new();
+    let mut scanner = Scanner { source: &source, path, package, classes: Vec::new(), findings: &mut findings };
+    scanner.visit(root, None);
+    Ok(findings)
+}
+
+struct Scanner<'a> {
+    source: &'a str,
+    path: &'a Path,
+    package: Option<String>,
+    /// 바깥부터 현재까지의 클래스 이름
+    classes: Vec<String>,
+    findings: &'a mut Vec<Finding>,
+}
+
+impl Scanner<'_> {
+    fn visit<'t>(&mut self, node: Node<'t>, scope: Option<Node<'t>>) {
+        let mut scope = scope;
+        let mut class = false;
+        match node.kind() {
+            "class_declaration" | "enum_declaration" | "interface_declaration" | "record_declaration" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    self.classes.push(node_text(name, self.source).to_string());
+                    class = true;
+                }
+            }
+            "method_declaration" | "constructor_declaration" => scope = Some(node),
+            "method_invocation" => self.check_call(node, scope),
+            _ => {}
+        }
+        for child in named_children(node) {
+            self.visit(child, scope);
+        }
+        if class {
+            self.classes.pop();
+        }
+    }
+
+    fn check_call(&mut self, node: Node, scope: Option<Node>) {
+        let text = |n: Node| node_text(n, self.source);
+        let Some(name) = node.child_by_field_name("name").map(text) else {
+            return;
+        };
+        let on_pending_intent = node.child_by_field_name("object")
+            .is_some_and(|o| matches!(text(o), "PendingIntent" | "android.app.PendingIntent"));
+        if !FACTORIES.contains(&name) || !on_pending_intent {
+            return;
+        }
+        let arguments = node.child_by_field_name("arguments").map(named_children).unwrap_or_default();
+        let (Some(intent), Some(flags)) = (arguments.get(2), arguments.get(3)) else {
+            return;
+        };
+
+        let target = if name == "getActivities" { Target::Unknown } else { self.target(*intent, scope, 0) };
+        let mutability = self.mutability(*flags, scope);
+        let (rule, severity) = match (target, mutability) {
+            (Target::Implicit, Mutability::Mutable | Mutability::Unspecified) => (&IMPLICIT_PENDING_INTENT, Severity::Error),
+            (Target::Implicit, _) => (&IMPLICIT_PENDING_INTENT, Severity::Warning),
+            (_, Mutability::Mutable) => (&MUTABLE_PENDING_INTENT, Severity::Warning),
+            (Target::Explicit, Mutability::Unspecified) => (&MUTABLE_PENDING_INTENT, Severity::Note),
+            _ => return,
+        };
+
+        let mut message = format!("PendingIntent.{}() ", name);
+        if target == Target::Implicit {
+            message.push_str(&format!("wraps an implicit intent ({})", short(text(*intent))));
+        } else {
+            message.push_str(&format!("wraps {}", short(text(*intent))));
+        }
+        message.push_str(match mutability {
+            Mutability::Mutable => " with FLAG_MUTABLE",
+            Mutability::Immutable => " with FLAG_IMMUTABLE",
+            Mutability::Unspecified => " without FLAG_IMMUTABLE (mutable before Android 12)",
+            Mutability::Unknown => "",
+        });
+        message.push_str(&format!(" [flags: {}]", short(text(*flags))));
+
+        let class_name = match &self.package {
+            Some(package) => format!("{}.{}", package, self.classes.join(".")),
+            None => self.classes.join("."),
+        };
+        self.findings.push(Finding::in_source(
+            rule,
+            severity,
+            class_name,
+            self.path,
+            node.start_position().row + 1,
+            message,
+        ));
+    }
+
+    /// Intent 식이 대상 컴포넌트(또는 패키지)를 정하는지
+    fn target(&self, node: Node, scope: Option<Node>, depth: usize) -> Target {
+        if depth > 4 {
+            return Target::Unknown;
+        }
+        let text = |n: Node| node_text(n, self.source);
+        match node.kind() {
+            "parenthesized_expression" | "cast_expression" => node.named_child(node.named_child_count().saturating_sub(1))
+                .map(|n| self.target(n, scope, depth + 1))
+                .unwrap_or(Target::Unknown),
+            "object_creation_expression" => {
+                let is_intent = node.child_by_field_name("type").is_some_and(|t| text(t).ends_with("Intent"));
+                if !is_intent {
+                    return Target::Unknown;
+                }
+                let arguments = node.child_by_field_name("arguments").map(named_children).unwrap_or_default();
+                if arguments.iter().any(|a| a.kind() == "class_literal") {
+                    return Target::Explicit;
+                }
+                match arguments.as_slice() {
+                    [] => Target::Implicit,
+                    // new Intent(action), new Intent(action, uri)
+                    [first, ..] if self.is_action(*first) => Target::Implicit,
+                    // new Intent(intent): 복사한 intent를 따라간다
+                    [argument] if argument.kind() == "identifier" => self.target(*argument, scope, depth + 1),
+                    // new Intent(context, cls), new Intent(action, uri, context, cls)
+                    [_, _] | [_, _, _, _] => Target::Explicit,
+                    _ => Target::Unknown,
+                }
+            }
+            "method_invocation" => {
+                let name = node.child_by_field_name("name").map(text).unwrap_or("");
+                if EXPLICIT_SETTERS.contains(&name) {
+                    return Target::Explicit;
+                }
+                match node.child_by_field_name("object") {
+                    Some(object) if BUILDERS.contains(&name) => self.target(object, scope, depth + 1),
+                    _ => Target::Unknown,
+                }
+            }
+            "identifier" => self.variable_target(text(node), scope, depth),
+            _ => Target::Unknown,
+        }
+    }
+
+    /// `new Intent(...)`의 첫 인자가 action 문자열이나 상수(`ACTION_VIEW`, `Intent.ACTION_SEND`)인지
+    fn is_action(&self, argument: Node) -> bool {
+        match argument.kind() {
+            "string_literal" => true,
+            "identifier" => is_constant(node_text(argument, self.source)),
+            "field_access" => argument.child_by_field_name("field")
+                .is_some_and(|field| is_constant(node_text(field, self.source))),
+            _ => false,
+        }
+    }
+
+    /// 메서드 안의 지역 변수: 대상을 정하는 호출이 있거나, 대입된 값으로 판단한다.
+    fn variable_target(&self, name: &str, scope: Option<Node>, depth: usize) -> Target {
+        let Some(scope) = scope else {
+            return Target::Unknown;
+        };
+        let mut values = Vec::new();
+        let mut explicit = false;
+        self.collect_variable(scope, name, &mut values, &mut explicit);
+        if explicit {
+            return Target::Explicit;
+        }
+        let targets: Vec<Target> = values.into_iter().map(|v| self.target(v, Some(scope), depth + 1)).collect();
+        if targets.contains(&Target::Explicit) {
+            Target::Explicit
+        } else if !targets.is_empty() && targets.iter().all(|t| *t == Target::Implicit) {
+            Target::Implicit
+        } else {
+            Target::Unknown
+        }
+    }
+
+    fn collect_variable<'t>(&self, node: Node<'t>, name: &str, values: &mut Vec<Node<'t>>, explicit: &mut bool) {
+        let text = |n: Node| node_text(n, self.source);
+        match node.kind() {
+            "variable_declarator" if node.child_by_field_name("name").is_some_and(|n| text(n) == name) => {
+                values.extend(node.child_by_field_name("value"));
+            }
+            "assignment_expression" if node.child_by_field_name("left").is_some_and(|n| text(n) == name) => {
+                values.extend(node.child_by_field_name("right"));
+            }
+            "method_invocation" => {
+                let on_variable = node.child_by_field_name("object").is_some_and(|o| text(o) == name);
+                let setter = node.child_by_field_name("name").is_some_and(|n| EXPLICIT_SETTERS.contains(&text(n)));
+                if on_variable && setter {
+                    *explicit = true;
+                }
+            }
+            _ => {}
+        }
+        for child in named_children(node) {
+            self.collect_variable(child, name, values, explicit);
+        }
+    }
+
+    fn mutability(&self, node: Node, scope: Option<Node>) -> Mutability {
+        let mut flags = node_text(node, self.source).to_string();
+        if node.kind() == "identifier" {
+            let mut values = Vec::new();
+            let mut explicit = false;
+            if let Some(scope) = scope {
+                self.collect_variable(scope, &flags, &mut values, &mut explicit);
+            }
+            match values.first() {
+                Some(value) => flags = node_text(*value, self.source).to_string(),
+                None if is_constant(&flags) => {}
+                None => return Mutability::Unknown,
+            }
+        }
+        if flags.contains("FLAG_MUTABLE") {
+            Mutability::Mutable
+        } else if flags.contains("FLAG_IMMUTABLE") {
+            Mutability::Immutable
+        } else if flags.contains("FLAG_") || flags.trim().chars().all(|c| c.is_ascii_digit()) {
+            Mutability::Unspecified
+        } else {
+            Mutability::Unknown
+        }
+    }
+}
+
+/// `ACTION_VIEW`, `FLAG_UPDATE_CURRENT` 같은 상수 이름
+fn is_constant(name: &str) -> bool {
+    name.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// 메시지에 넣을 한 줄짜리 코드
+fn short(code: &str) -> String {
+    let code = code.split_whitespace().collect::<Vec<_>>().join(" ");
+    if code.chars().count() > MAX_CODE_LENGTH {
+        format!("{}...", code.chars().take(MAX_CODE_LENGTH).collect::<String>())
+    } else {
+        code
+    }
+}
@@ -0,0 +1,103 @@
+use std::path::Path;
+use tracing::warn;
+use crate::manifest::Component;
+use crate::rules::{Finding, Rule, Severity};
+use crate::utils::dataflow::{self, TaintFlow, TaintSpec};
+use crate::utils::provider::ProviderSource;
+
+pub const PROVIDER_PATH_TRAVERSAL: Rule = Rule {
+    id: "AINT011",
+    name: "ProviderPathTraversal",
+    description: "ContentProvider opens a file built from the request URI without canonicalizing it, so `..%2F` segments can read or write other files of the app.",
+    severity: Severity::Error,
+};
+
+pub const RULES: &[Rule] = &[PROVIDER_PATH_TRAVERSAL];
+
+/// 요청 URI에서 경로를 꺼내는 호출
+const SOURCES: &[&str] = &[
+    "getLastPathSegment",
+    "getPath",
+    "getEncodedPath",
+    "getPathSegments",
+    "getQueryParameter",
+];
+
+const PASSTHROUGH: &[&str] = &["decode", "valueOf", "format", "concat"];
+
+const WRAPPER_TYPES: &[&str] = &["File"];
+
+/// 파일을 여는 호출 (`ParcelFileDescriptor.open`, `AssetFileDescriptor` 생성 등)
+const SINKS: &[&str] = &["open", "openFileDescriptor", "openAssetFileDescriptor"];
+
+/// 밖에서 요청할 수 있는 provider인지 (exported이거나 URI 권한을 넘겨줄 수 있는지)
+pub fn reachable(component: &Component) -> bool {
+    component.is_effectively_exported()
+        || component.provider.as_ref().is_some_and(|p| p.grant_uri_permissions || !p.grant_uri_paths.is_empty())
+}
+
+/// `openFile` 등에서 URI의 경로가 검사 없이 파일 열기에 쓰이는 경로를 찾는다.
+pub fn check(component: &Component, source_file: &Path, source: &ProviderSource) -> (Vec<TaintFlow>, Vec<Finding>) {
+    if component.component_type != "provider" || source.file_methods.is_empty() || !reachable(component) {
+        return (Vec::new(), Vec::new());
+    }
+
+    let spec = TaintSpec {
+        sources: SOURCES,
+        passthrough: PASSTHROUGH,
+        copy_types: &[],
+        wrapper_types: WRAPPER_TYPES,
+        sinks: SINKS,
+    };
+    let flows = match dataflow::find_flows_in_file(source_file, &spec) {
+        Ok(flows) => flows,
+        Err(e) => {
+            warn!("Failed to analyze data flow in {}: {}", source_file.display(), e);
+            return (Vec::new(), Vec::new());
+        }
+    };
+
+    let mut traversals = Vec::new();
+    let mut findings = Vec::new();
+    for flow in flows {
+        let Some(method) = source.file_method_at(flow.sink.line) else {
+            continue;
+        };
+        // File.getPath()도 같은 이름이므로 URI에서 꺼낸 경우만 본다
+        let from_file = matches!(flow.source_method.as_str(), "getPath" | "getEncodedPath")
+            && !flow.source.code.to_lowercase().contains("uri");
+        if method.checks_path || from_file {
+            continue;
+        }
+        let mut finding = Finding::new(
+            &PROVIDER_PATH_TRAVERSAL,
+            if component.is_effectively_exported() { Severity::Error } else { Severity::Warning },
+            component,
+            format!(
+                "{}.{}() opens a file from the URI's {}() without canonicalizing it [{}]{}",
+                component.name,
+                method.name,
+                flow.source_method,
+                flow.describe_path(),
+                if component.is_effectively_exported() { "" } else { "; reachable through granted URI permissions" }
+            ),
+        );
+        finding.location.path = source_file.to_path_buf();
+        finding.location.line = flow.sink.line;
+        finding.location.column = None;
+        findings.push(finding);
+        traversals.push(flow);
+    }
+    (traversals, findings)
+}
+
+/// `content read`로 앱의 기본 SharedPreferences를 읽어보는 URI
+pub fn traversal_uri(content_uri: &str, package: &str) -> String {
+    format!(
+        "{}/{}data%2Fdata%2F{}%2Fshared_prefs%2F{}_preferences.xml",
+        content_uri.trim_end_matches('/'),
+        "..%2F".repeat(8),
+        package,
+        package
+    )
+}
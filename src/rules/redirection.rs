@@ -0,0 +1,147 @@
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+use crate::export::qualified_class_name;
+use crate::manifest::Component;
+use crate::rules::{Finding, Rule, Severity};
+use crate::utils::dataflow::{self, TaintFlow, TaintSpec};
+
+pub const INTENT_REDIRECTION: Rule = Rule {
+    id: "AINT008",
+    name: "IntentRedirection",
+    description: "Exported component takes an Intent from its extras and starts, sends or returns it, letting callers reach non-exported components or gain URI permissions with the app's identity.",
+    severity: Severity::Error,
+};
+
+pub const RULES: &[Rule] = &[INTENT_REDIRECTION];
+
+/// 다른 intent를 꺼낼 수 있는 호출
+const SOURCES: &[&str] = &[
+    "getParcelableExtra",
+    "getParcelableArrayExtra",
+    "getParcelableArrayListExtra",
+    "getBundleExtra",
+    "getExtras",
+    "getParcelable",
+    "getParcelableArray",
+    "getParcelableArrayList",
+];
+
+const PASSTHROUGH: &[&str] = &["createChooser", "parseUri", "getIntentOrNull"];
+
+const COPY_TYPES: &[&str] = &["Intent"];
+
+const ACTIVITY_SINKS: &[&str] = &[
+    "startActivity",
+    "startActivityForResult",
+    "startActivities",
+    "startActivityIfNeeded",
+    "startNextMatchingActivity",
+    "setResult",
+];
+const SERVICE_SINKS: &[&str] = &["startService", "startForegroundService", "bindService"];
+const RECEIVER_SINKS: &[&str] = &["sendBroadcast", "sendOrderedBroadcast", "sendStickyBroadcast"];
+
+/// extra에서 꺼낸 intent가 싱크에 도달하는 경로와 PoC에 넣을 중첩 intent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntentRedirection {
+    #[serde(flatten)]
+    pub flow: TaintFlow,
+    /// 중첩 intent가 열 같은 패키지의 비공개 컴포넌트
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+}
+
+impl IntentRedirection {
+    /// 싱크에 맞는 중첩 intent 설명
+    pub fn payload(&self, package: &str) -> String {
+        let extra = self.flow.key.as_deref().unwrap_or("<extra>");
+        if self.flow.sink_method == "setResult" {
+            return format!(
+                "extra '{}' = Intent{{data=content://<authority>/<path>, flags=FLAG_GRANT_READ_URI_PERMISSION|FLAG_GRANT_WRITE_URI_PERMISSION}}",
+                extra
+            );
+        }
+        match &self.target {
+            Some(target) => format!("extra '{}' = Intent{{cmp={}/{}}}", extra, package, target),
+            None => format!("extra '{}' = Intent{{cmp={}/<non-exported {}>}}", extra, package, target_type(&self.flow.sink_method)),
+        }
+    }
+}
+
+/// 싱크가 시작하는 컴포넌트 종류
+pub fn target_type(sink_method: &str) -> &'static str {
+    if SERVICE_SINKS.contains(&sink_method) {
+        "service"
+    } else if RECEIVER_SINKS.contains(&sink_method) {
+        "receiver"
+    } else {
+        "activity"
+    }
+}
+
+/// exported 컴포넌트의 Java 소스에서 extra의 intent가 싱크로 가는 경로를 찾는다.
+///
+/// `components`는 중첩 intent의 대상으로 쓸 같은 패키지의 비공개 컴포넌트를 고르는 데 쓴다.
+pub fn check(component: &Component, source_file: &Path, components: &[Component]) -> (Vec<IntentRedirection>, Vec<Finding>) {
+    if !component.is_effectively_exported() || component.component_type == "provider" {
+        return (Vec::new(), Vec::new());
+    }
+    let is_java = source_file.extension().is_some_and(|e| e == "java");
+    if !is_java {
+        debug!("Skipping intent redirection check for non-Java source {}", source_file.display());
+        return (Vec::new(), Vec::new());
+    }
+
+    let sinks: Vec<&str> = ACTIVITY_SINKS.iter()
+        .chain(SERVICE_SINKS)
+        .chain(RECEIVER_SINKS)
+        .copied()
+        .collect();
+    let spec = TaintSpec {
+        sources: SOURCES,
+        passthrough: PASSTHROUGH,
+        copy_types: COPY_TYPES,
+        wrapper_types: &[],
+        sinks: &sinks,
+    };
+    let flows = match dataflow::find_flows_in_file(source_file, &spec) {
+        Ok(flows) => flows,
+        Err(e) => {
+            warn!("Failed to analyze data flow in {}: {}", source_file.display(), e);
+            return (Vec::new(), Vec::new());
+        }
+    };
+
+    let mut redirections = Vec::new();
+    let mut findings = Vec::new();
+    for flow in flows {
+        let target = components.iter()
+            .filter(|c| !c.is_effectively_exported() && c.package == component.package)
+            .find(|c| c.component_type == target_type(&flow.sink_method))
+            .map(qualified_class_name);
+        let redirection = IntentRedirection { flow, target };
+
+        let mut finding = Finding::new(
+            &INTENT_REDIRECTION,
+            INTENT_REDIRECTION.severity,
+            component,
+            format!(
+                "{} passes an Intent from {} to {}() [{}]; payload: {}",
+                component.name,
+                redirection.flow.key.as_ref()
+                    .map(|k| format!("extra '{}'", k))
+                    .unwrap_or_else(|| "its extras".to_string()),
+                redirection.flow.sink_method,
+                redirection.flow.describe_path(),
+                redirection.payload(&component.package)
+            ),
+        );
+        finding.location.path = source_file.to_path_buf();
+        finding.location.line = redirection.flow.sink.line;
+        finding.location.column = None;
+        findings.push(finding);
+        redirections.push(redirection);
+    }
+    (redirections, findings)
+}
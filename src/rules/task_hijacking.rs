@@ -0,0 +1,81 @@
+use crate::manifest::Component;
+use crate::rules::{Finding, Rule, Severity};
+
+pub const TASK_HIJACKING: Rule = Rule {
+    id: "AINT015",
+    name: "TaskHijacking",
+    description: "Activity shares a task affinity that another app can claim (launchMode=\"singleTask\", allowTaskReparenting, a custom taskAffinity, or the default affinity of a launcher activity), so a malicious app can place its own activities in the victim's task (StrandHogg).",
+    severity: Severity::Warning,
+};
+
+pub const RULES: &[Rule] = &[TASK_HIJACKING];
+
+/// 이 targetSdk부터는 플랫폼의 태스크 하이재킹 완화가 적용된다 (Android 11)
+const MITIGATED_TARGET_SDK: u32 = 30;
+
+/// activity의 launchMode, taskAffinity, allowTaskReparenting과 앱의 targetSdk로 태스크 하이재킹 가능성을 본다.
+pub fn check(component: &Component) -> Vec<Finding> {
+    let Some(activity) = &component.activity else {
+        return Vec::new();
+    };
+    if component.component_type != "activity" || component.dynamic.is_some() {
+        return Vec::new();
+    }
+    let affinity = activity.effective_task_affinity(&component.package);
+    // affinity가 없거나 항상 혼자 태스크를 쓰는 activity는 다른 앱의 태스크와 섞이지 않는다
+    let launch_mode = activity.launch_mode.as_deref().unwrap_or("standard");
+    if affinity.is_empty() || matches!(launch_mode, "singleInstance" | "singleInstancePerTask") {
+        return Vec::new();
+    }
+
+    let mut reasons = Vec::new();
+    if launch_mode == "singleTask" {
+        reasons.push("launchMode=\"singleTask\"".to_string());
+    }
+    if activity.allows_task_reparenting() {
+        reasons.push("allowTaskReparenting=\"true\"".to_string());
+    }
+    if affinity != component.package {
+        reasons.push(format!("taskAffinity=\"{}\"", affinity));
+    }
+    // 런처 activity는 android:exported="false"로 막지 않는 한 exported다 (targetSdk와 무관)
+    let explicitly_private = component.declared_attributes.contains("exported") && !component.exported;
+    let launcher = !explicitly_private
+        && component.actions.contains("android.intent.action.MAIN")
+        && component.categories.contains("android.intent.category.LAUNCHER");
+
+    let mitigated = component.target_sdk.is_some_and(|sdk| sdk >= MITIGATED_TARGET_SDK);
+    let severity = if !reasons.is_empty() {
+        if mitigated { Severity::Note } else { Severity::Warning }
+    } else if launcher && !mitigated {
+        // 기본 affinity의 런처 activity는 StrandHogg의 기본 대상이다
+        reasons.push(format!("launcher activity with the default taskAffinity \"{}\"", affinity));
+        if component.target_sdk.is_some() { Severity::Warning } else { Severity::Note }
+    } else {
+        return Vec::new();
+    };
+
+    let mut details = Vec::new();
+    if activity.exclude_from_recents == Some(true) {
+        details.push("excludeFromRecents=\"true\"".to_string());
+    }
+    if let Some(document_launch_mode) = &activity.document_launch_mode {
+        details.push(format!("documentLaunchMode=\"{}\"", document_launch_mode));
+    }
+    details.push(match component.target_sdk {
+        Some(sdk) => format!("targetSdk {}", sdk),
+        None => "targetSdk unknown".to_string(),
+    });
+
+    vec![Finding::new(
+        &TASK_HIJACKING,
+        severity,
+        component,
+        format!(
+            "{} can share its task with another app's activities: {} ({})",
+            component.name,
+            reasons.join(", "),
+            details.join(", ")
+        ),
+    )]
+}
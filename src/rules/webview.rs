@@ -0,0 +1,220 @@
+use std::path::Path;
+use tracing::{debug, warn};
+use crate::manifest::Component;
+use crate::rules::{Finding, Rule, Severity};
+use crate::utils::dataflow::{self, CallSite, TaintFlow, TaintSpec};
+
+pub const WEBVIEW_URL_INJECTION: Rule = Rule {
+    id: "AINT009",
+    name: "WebViewUrlInjection",
+    description: "Exported component loads a URL, HTML or script taken from its intent into a WebView, so callers and web pages can make it show their content with the app's WebView settings and JavaScript bridges.",
+    severity: Severity::Error,
+};
+
+pub const UNSAFE_WEBVIEW_SETTINGS: Rule = Rule {
+    id: "AINT010",
+    name: "UnsafeWebViewSettings",
+    description: "Exported component configures a WebView with JavaScript, a JavaScript interface, file or content access, or file URL access, which any page it is made to load can use.",
+    severity: Severity::Warning,
+};
+
+pub const RULES: &[Rule] = &[WEBVIEW_URL_INJECTION, UNSAFE_WEBVIEW_SETTINGS];
+
+const SOURCES: &[&str] = &[
+    "getStringExtra",
+    "getCharSequenceExtra",
+    "getBundleExtra",
+    "getExtras",
+    "getData",
+    "getDataString",
+];
+
+const PASSTHROUGH: &[&str] = &["parse", "valueOf", "decode", "format", "concat"];
+
+const SINKS: &[&str] = &[
+    "loadUrl",
+    "loadData",
+    "loadDataWithBaseURL",
+    "postUrl",
+    "evaluateJavascript",
+];
+
+const SETTINGS: &[&str] = &[
+    "setJavaScriptEnabled",
+    "setAllowFileAccess",
+    "setAllowContentAccess",
+    "setAllowFileAccessFromFileURLs",
+    "setAllowUniversalAccessFromFileURLs",
+    "addJavascriptInterface",
+];
+
+/// WebView에 넣어볼 테스트 URL
+pub const TEST_URL: &str = "https://aintent.invalid/webview-test";
+/// evaluateJavascript에 넣어볼 스크립트 (뒤에 붙는 코드는 주석 처리된다)
+pub const TEST_SCRIPT: &str = "alert(document.domain)//";
+
+/// 테스트 값을 넣을 intent 위치
+#[derive(Debug, Clone, PartialEq)]
+pub enum PayloadSlot {
+    /// 문자열 extra
+    Extra(String),
+    /// intent data URI 전체
+    Data,
+    /// intent data URI의 쿼리 파라미터
+    DataQuery(String),
+}
+
+/// intent에서 WebView 싱크로 가는 경로 하나
+#[derive(Debug, Clone)]
+pub struct WebViewFlow {
+    pub flow: TaintFlow,
+}
+
+impl WebViewFlow {
+    pub fn slot(&self) -> Option<PayloadSlot> {
+        let from_data = matches!(self.flow.source_method.as_str(), "getData" | "getDataString");
+        match (&self.flow.key, from_data) {
+            (Some(key), true) => Some(PayloadSlot::DataQuery(key.clone())),
+            (None, true) => Some(PayloadSlot::Data),
+            (Some(key), false) => Some(PayloadSlot::Extra(key.clone())),
+            // getExtras()에서 이름을 모르는 값
+            (None, false) => None,
+        }
+    }
+
+    pub fn test_value(&self) -> &'static str {
+        if self.flow.sink_method == "evaluateJavascript" {
+            TEST_SCRIPT
+        } else {
+            TEST_URL
+        }
+    }
+
+    fn describe_source(&self) -> String {
+        match self.slot() {
+            Some(PayloadSlot::Extra(key)) => format!("extra '{}'", key),
+            Some(PayloadSlot::Data) => "the intent data URI".to_string(),
+            Some(PayloadSlot::DataQuery(key)) => format!("query parameter '{}' of the data URI", key),
+            None => "its extras".to_string(),
+        }
+    }
+}
+
+/// exported 컴포넌트의 Java 소스에서 intent 값이 WebView로 가는 경로와 WebView 설정을 검사한다.
+pub fn check(component: &Component, source_file: &Path) -> (Vec<WebViewFlow>, Vec<Finding>) {
+    if !component.is_effectively_exported() || component.component_type != "activity" {
+        return (Vec::new(), Vec::new());
+    }
+    if source_file.extension().is_none_or(|e| e != "java") {
+        debug!("Skipping WebView check for non-Java source {}", source_file.display());
+        return (Vec::new(), Vec::new());
+    }
+
+    let spec = TaintSpec {
+        sources: SOURCES,
+        passthrough: PASSTHROUGH,
+        copy_types: &[],
+        wrapper_types: &[],
+        sinks: SINKS,
+    };
+    let (flows, settings) = match dataflow::find_flows_in_file(source_file, &spec)
+        .and_then(|flows| Ok((flows, dataflow::find_calls_in_file(source_file, SETTINGS)?)))
+    {
+        Ok(result) => result,
+        Err(e) => {
+            warn!("Failed to analyze WebView usage in {}: {}", source_file.display(), e);
+            return (Vec::new(), Vec::new());
+        }
+    };
+
+    let enabled: Vec<&CallSite> = settings.iter()
+        .filter(|call| call.method == "addJavascriptInterface" || call.arguments.first().is_some_and(|a| a == "true"))
+        .collect();
+    let bridges: Vec<String> = enabled.iter()
+        .filter(|call| call.method == "addJavascriptInterface")
+        .map(|call| call.arguments.get(1).map(|a| a.trim_matches('"').to_string()).unwrap_or_default())
+        .collect();
+    let javascript = enabled.iter().any(|call| call.method == "setJavaScriptEnabled");
+
+    let mut findings = Vec::new();
+    let flows: Vec<WebViewFlow> = flows.into_iter().map(|flow| WebViewFlow { flow }).collect();
+    for webview_flow in &flows {
+        let flow = &webview_flow.flow;
+        let scripted = javascript || !bridges.is_empty() || flow.sink_method == "evaluateJavascript";
+        let mut message = format!(
+            "{} passes {} to WebView.{}() [{}]",
+            component.name,
+            webview_flow.describe_source(),
+            flow.sink_method,
+            flow.describe_path()
+        );
+        if !enabled.is_empty() {
+            message.push_str(&format!("; WebView settings: {}", describe_settings(&enabled)));
+        }
+        let mut finding = Finding::new(
+            &WEBVIEW_URL_INJECTION,
+            if scripted { Severity::Error } else { Severity::Warning },
+            component,
+            message,
+        );
+        finding.location.path = source_file.to_path_buf();
+        finding.location.line = flow.sink.line;
+        finding.location.column = None;
+        findings.push(finding);
+    }
+
+    // 자바스크립트 인터페이스와 file URL 권한은 항상, 나머지 설정은 intent 값이 WebView로 갈 때 warning
+    for call in &enabled {
+        let severity = match call.method.as_str() {
+            "addJavascriptInterface" | "setAllowFileAccessFromFileURLs" | "setAllowUniversalAccessFromFileURLs" => Severity::Warning,
+            _ if !flows.is_empty() => Severity::Warning,
+            _ => Severity::Note,
+        };
+        let mut finding = Finding::new(
+            &UNSAFE_WEBVIEW_SETTINGS,
+            severity,
+            component,
+            format!(
+                "{} configures its WebView with {} (line {}: {})",
+                component.name,
+                describe_settings(&[call]),
+                call.step.line,
+                call.step.code
+            ),
+        );
+        finding.location.path = source_file.to_path_buf();
+        finding.location.line = call.step.line;
+        finding.location.column = None;
+        findings.push(finding);
+    }
+    (flows, findings)
+}
+
+fn describe_settings(enabled: &[&CallSite]) -> String {
+    let mut settings: Vec<String> = enabled.iter()
+        .map(|call| match call.method.as_str() {
+            "addJavascriptInterface" => format!(
+                "JavaScript interface '{}'",
+                call.arguments.get(1).map(|a| a.trim_matches('"')).unwrap_or("?")
+            ),
+            "setJavaScriptEnabled" => "JavaScript enabled".to_string(),
+            "setAllowFileAccess" => "file access".to_string(),
+            "setAllowContentAccess" => "content access".to_string(),
+            "setAllowFileAccessFromFileURLs" => "file access from file URLs".to_string(),
+            _ => "universal access from file URLs".to_string(),
+        })
+        .collect();
+    settings.sort();
+    settings.dedup();
+    settings.join(", ")
+}
+
+/// 쿼리 파라미터 값으로 넣을 수 있게 percent-encoding한다.
+pub fn percent_encode(value: &str) -> String {
+    value.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
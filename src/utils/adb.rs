@@ -1,4 +1,6 @@
 use crate::manifest::Component;
+use crate::manifest::uri::example_uris;
+use crate::export::qualified_class_name;
 use anyhow::Result;
 use crate::llm::analyzer::IntentParameter;
 
@@ -6,6 +8,8 @@ pub struct ADBCommand {
     component: Option<Component>,
     intent_params: Vec<IntentParameter>,
     extra_args: Vec<String>,
+    /// provider에 `content query`로 보낼 URI
+    content_uri: Option<String>,
 }
 
 impl ADBCommand {
@@ -14,17 +18,30 @@ impl ADBCommand {
             component: None,
             intent_params: Vec::new(),
             extra_args: Vec::new(),
+            content_uri: None,
         })
     }
 
     pub fn set_component(&mut self, component: &Component) {
         self.component = Some(component.clone());
+        // 이전 컴포넌트의 파라미터가 남지 않도록 초기화
+        self.intent_params.clear();
+        self.extra_args.clear();
+        self.content_uri = None;
+    }
+
+    pub fn set_content_uri(&mut self, uri: &str) {
+        self.content_uri = Some(uri.to_string());
     }
 
     pub fn set_intent_params(&mut self, params: &[IntentParameter]) {
         self.intent_params = params.to_vec();
     }
 
+    pub fn intent_params(&self) -> &[IntentParameter] {
+        &self.intent_params
+    }
+
     pub fn add_extra_arg(&mut self, arg: &str) {
         // Check if the argument already exists to avoid duplicates
         if !self.extra_args.contains(&arg.to_string()) {
@@ -33,58 +50,109 @@ impl ADBCommand {
     }
 
     pub fn build_command(&self) -> Result<String> {
+        self.build_filtered(|_| true, |_| true, None)
+    }
+
+    /// `-d`를 주어진 URI로 바꾼 명령어
+    pub fn build_with_data(&self, uri: &str) -> Result<String> {
+        let arg = format!("-d {}", shell_quote(uri));
+        self.build_filtered(|p| p.flag != "-d", |_| true, Some(&arg))
+    }
+
+    /// 같은 이름의 extra를 문자열 값으로 바꾼 명령어
+    pub fn build_with_string_extra(&self, name: &str, value: &str) -> Result<String> {
+        let arg = format!("--es {} {}", name, shell_quote(value));
+        self.build_filtered(
+            |p| !(p.flag == "-e" && p.name == name),
+            |a| a.split_whitespace().nth(1) != Some(name),
+            Some(&arg),
+        )
+    }
+
+    fn build_filtered(
+        &self,
+        keep_param: impl Fn(&IntentParameter) -> bool,
+        keep_arg: impl Fn(&str) -> bool,
+        extra: Option<&str>,
+    ) -> Result<String> {
         let component = self.component.as_ref()
             .ok_or_else(|| anyhow::anyhow!("No component set"))?;
 
-        // 컴포넌트 이름이 패키지명으로 시작하는지 확인
-        let component_name = if component.name.starts_with(&component.package) {
-            // 패키지명으로 시작하면 패키지명을 제외한 나머지 부분만 사용
-            component.name[component.package.len()..].trim_start_matches('.')
-        } else {
-            // 패키지명으로 시작하지 않으면 전체 이름 사용
-            &component.name
-        };
+        // provider는 intent가 아니라 URI로 호출한다
+        if component.component_type == "provider" {
+            let uri = self.content_uri.clone()
+                .unwrap_or_else(|| format!("content://{}", component.package));
+            return Ok(content_command("query", &uri));
+        }
 
-        // 컴포넌트 이름이 '.'으로 시작하지 않는 경우 추가
-        let component_name = if !component_name.starts_with('.') {
-            format!(".{}", component_name)
-        } else {
-            component_name.to_string()
+        let verb = match component.component_type.as_str() {
+            "service" => "startservice",
+            "receiver" => "broadcast",
+            _ => "start",
         };
-
-        // 컴포넌트 이름이 패키지명을 포함하는지 한 번 더 확인
-        let final_component_name = if component_name.contains(&component.package) {
-            // 패키지명을 제외한 부분만 사용
-            component_name.split(&component.package)
-                .last()
-                .unwrap_or(&component_name)
-                .trim_start_matches('.')
-                .to_string()
+        let mut command = if component.dynamic.is_some() {
+            // 동적 receiver는 클래스 이름으로 지정할 수 없으므로 패키지와 action으로 보낸다
+            let mut command = format!("adb shell am broadcast -p {}", component.package);
+            let has_param = |flag: &str| self.intent_params.iter().any(|p| p.flag == flag && keep_param(p));
+            if let (false, Some(action)) = (has_param("-a"), component.actions.iter().next()) {
+                command.push_str(&format!(" -a {}", shell_quote(action)));
+            }
+            // data 조건이 있는 필터는 data가 없는 intent와 맞지 않는다
+            let replaces_data = extra.is_some_and(|e| e.starts_with("-d "));
+            if !has_param("-d") && !replaces_data {
+                if let Some(uri) = example_uris(component).into_iter().next() {
+                    command.push_str(&format!(" -d {}", shell_quote(&uri)));
+                }
+            }
+            command
         } else {
-            component_name
+            format!("adb shell am {} -n {}", verb, component_argument(component))
         };
 
-        let mut command = format!(
-            "adb shell am start -n {}/{}",
-            component.package,
-            final_component_name
-        );
-
         // Add intent parameters
-        for param in &self.intent_params {
+        for param in self.intent_params.iter().filter(|p| keep_param(p)) {
             command.push(' ');
             command.push_str(&param.to_string());
         }
 
         // Add extra arguments
-        for arg in &self.extra_args {
+        for arg in self.extra_args.iter().filter(|a| keep_arg(a)) {
             command.push_str(&format!(" {}", arg));
         }
+        if let Some(extra) = extra {
+            command.push_str(&format!(" {}", extra));
+        }
 
         Ok(command)
     }
 }
 
+/// `-n` 인자 (`<applicationId>/<클래스>`).
+///
+/// `.Class` 축약은 클래스가 패키지 안에 있을 때만 쓴다. applicationId가 namespace와 다르거나
+/// 라이브러리의 컴포넌트이면 전체 클래스 이름을 써야 한다.
+pub fn component_argument(component: &Component) -> String {
+    let class_name = qualified_class_name(component);
+    match class_name.strip_prefix(&format!("{}.", component.package)) {
+        Some(relative) => format!("{}/.{}", component.package, relative),
+        None => format!("{}/{}", component.package, class_name),
+    }
+}
+
+/// `adb shell content <verb> --uri <uri>`
+pub fn content_command(verb: &str, uri: &str) -> String {
+    format!("adb shell content {} --uri {}", verb, shell_quote(uri))
+}
+
+/// `adb shell`은 로컬 셸과 기기 셸을 모두 거치므로 특수 문자가 있으면 두 번 감싼다.
+fn shell_quote(value: &str) -> String {
+    if value.chars().all(|c| c.is_ascii_alphanumeric() || "/:._-=%".contains(c)) {
+        value.to_string()
+    } else {
+        format!("\"'{}'\"", value.replace('\'', "'\\''"))
+    }
+}
+
 #[allow(dead_code)]
 pub fn generate_adb_commands(component: &Component) -> Vec<String> {
     let mut commands = Vec::new();
@@ -96,18 +164,19 @@ pub fn generate_adb_commands(component: &Component) -> Vec<String> {
         _ => "activity",
     };
 
-    let component_name = if component.name.starts_with('.') {
-        format!("{}{}", component.package, component.name)
-    } else {
-        component.name.clone()
-    };
+    let component_name = component_argument(component);
 
     // 기본 명령어 생성
     let base_command = match component_type {
-        "activity" => format!("adb shell am start -n {}/{}", component.package, component_name),
-        "service" => format!("adb shell am startservice -n {}/{}", component.package, component_name),
-        "broadcast" => format!("adb shell am broadcast -n {}/{}", component.package, component_name),
-        "content" => format!("adb shell content call --uri content://{}/{}", component.package, component_name),
+        "activity" => format!("adb shell am start -n {}", component_name),
+        "service" => format!("adb shell am startservice -n {}", component_name),
+        "broadcast" => format!("adb shell am broadcast -n {}", component_name),
+        "content" => {
+            let authority = component.provider.as_ref()
+                .and_then(|p| p.authorities.first())
+                .unwrap_or(&component.package);
+            content_command("query", &format!("content://{}", authority))
+        }
         _ => return commands,
     };
 
@@ -123,16 +192,12 @@ pub fn generate_adb_commands(component: &Component) -> Vec<String> {
         let mut command = base_command.clone();
         
         // action 추가
-        if component_type == "broadcast" {
-            command = format!("{} -a {}", command, action);
-        } else {
-            command = format!("{} -a {}", command, action);
-        }
+        command = format!("{} -a {}", command, action);
 
         // 데이터 URI 추가 (scheme, host, path)
         if !component.data_schemes.is_empty() {
             for scheme in &component.data_schemes {
-                let data_uri = format!("{}", scheme);
+                let data_uri = scheme.to_string();
                 
                 // host 추가
                 if !component.data_hosts.is_empty() {
@@ -150,11 +215,7 @@ pub fn generate_adb_commands(component: &Component) -> Vec<String> {
                                     commands.push(data_command.clone());
                                 } else {
                                     for category in &component.categories {
-                                        let category_command = if component_type == "broadcast" {
-                                            format!("{} -c {}", data_command, category)
-                                        } else {
-                                            format!("{} -c {}", data_command, category)
-                                        };
+                                        let category_command = format!("{} -c {}", data_command, category);
                                         commands.push(category_command);
                                     }
                                 }
@@ -168,11 +229,7 @@ pub fn generate_adb_commands(component: &Component) -> Vec<String> {
                                 commands.push(data_command.clone());
                             } else {
                                 for category in &component.categories {
-                                    let category_command = if component_type == "broadcast" {
-                                        format!("{} -c {}", data_command, category)
-                                    } else {
-                                        format!("{} -c {}", data_command, category)
-                                    };
+                                    let category_command = format!("{} -c {}", data_command, category);
                                     commands.push(category_command);
                                 }
                             }
@@ -187,11 +244,7 @@ pub fn generate_adb_commands(component: &Component) -> Vec<String> {
                         commands.push(data_command.clone());
                     } else {
                         for category in &component.categories {
-                            let category_command = if component_type == "broadcast" {
-                                format!("{} -c {}", data_command, category)
-                            } else {
-                                format!("{} -c {}", data_command, category)
-                            };
+                            let category_command = format!("{} -c {}", data_command, category);
                             commands.push(category_command);
                         }
                     }
@@ -207,11 +260,7 @@ pub fn generate_adb_commands(component: &Component) -> Vec<String> {
                     commands.push(mime_command.clone());
                 } else {
                     for category in &component.categories {
-                        let category_command = if component_type == "broadcast" {
-                            format!("{} -c {}", mime_command, category)
-                        } else {
-                            format!("{} -c {}", mime_command, category)
-                        };
+                        let category_command = format!("{} -c {}", mime_command, category);
                         commands.push(category_command);
                     }
                 }
@@ -226,11 +275,7 @@ pub fn generate_adb_commands(component: &Component) -> Vec<String> {
 
             // 각 category에 대해 명령어 생성
             for category in &component.categories {
-                let category_command = if component_type == "broadcast" {
-                    format!("{} -c {}", command, category)
-                } else {
-                    format!("{} -c {}", command, category)
-                };
+                let category_command = format!("{} -c {}", command, category);
                 commands.push(category_command);
             }
         }
@@ -241,7 +286,7 @@ pub fn generate_adb_commands(component: &Component) -> Vec<String> {
         let command = base_command.clone();
         
         for scheme in &component.data_schemes {
-            let data_uri = format!("{}", scheme);
+            let data_uri = scheme.to_string();
             
             // host 추가
             if !component.data_hosts.is_empty() {
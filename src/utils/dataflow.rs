@@ -0,0 +1,455 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tree_sitter::{Node, Parser, Tree};
+use tree_sitter_java::language;
+
+/// 필드를 통해 메서드 사이로 전파되는 값을 따라가기 위해 파일 전체를 반복하는 횟수
+const FIELD_PASSES: usize = 3;
+const MAX_CODE_LENGTH: usize = 120;
+
+/// 오염 분석에 쓸 소스, 전파, 싱크 메서드 이름
+#[derive(Debug, Clone, Copy)]
+pub struct TaintSpec<'a> {
+    /// 반환값이 외부 입력인 메서드 (getParcelableExtra, getStringExtra, ...)
+    pub sources: &'a [&'a str],
+    /// 인자로 받은 오염 값을 그대로 돌려주는 메서드 (Intent.parseUri, Uri.parse, ...)
+    pub passthrough: &'a [&'a str],
+    /// 생성자 인자 하나를 복사하는 타입 (`new Intent(intent)`)
+    pub copy_types: &'a [&'a str],
+    /// 인자 중 하나라도 오염되면 결과도 오염되는 타입 (`new File(dir, name)`)
+    pub wrapper_types: &'a [&'a str],
+    /// 오염 값을 인자로 받으면 보고할 메서드
+    pub sinks: &'a [&'a str],
+}
+
+/// 값이 지나간 코드 한 줄 (줄 번호는 1부터 시작)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlowStep {
+    pub line: usize,
+    pub code: String,
+}
+
+/// 소스에서 싱크까지의 경로 하나
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaintFlow {
+    /// 소스 호출의 첫 번째 문자열 인자 (extra 이름)
+    pub key: Option<String>,
+    pub source_method: String,
+    pub source: FlowStep,
+    /// 소스와 싱크 사이에서 값을 받은 변수와 필드
+    pub steps: Vec<FlowStep>,
+    pub sink_method: String,
+    pub sink: FlowStep,
+}
+
+impl TaintFlow {
+    /// `line 12: ... → line 20: ...` 형태의 요약
+    pub fn describe_path(&self) -> String {
+        std::iter::once(&self.source)
+            .chain(&self.steps)
+            .chain(std::iter::once(&self.sink))
+            .map(|step| format!("line {}: {}", step.line, step.code))
+            .collect::<Vec<_>>()
+            .join(" → ")
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Taint {
+    key: Option<String>,
+    source_method: String,
+    source: FlowStep,
+    steps: Vec<FlowStep>,
+}
+
+/// Java 소스 파일 하나에서 소스의 값이 싱크 인자로 흘러가는 경로를 찾는다.
+///
+/// 메서드 안의 지역 변수는 선언 순서대로, 필드는 파일 전체에서 따라간다.
+/// 타입은 보지 않고 메서드 이름만으로 판단하므로 결과는 근사치다.
+pub fn find_flows_in_file(path: &Path, spec: &TaintSpec) -> Result<Vec<TaintFlow>> {
+    let source_code = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read source file: {}", e))?;
+    find_flows(&source_code, spec)
+}
+
+pub fn find_flows(source_code: &str, spec: &TaintSpec) -> Result<Vec<TaintFlow>> {
+    let tree = parse(source_code)?;
+
+    let mut methods = Vec::new();
+    collect_methods(tree.root_node(), &mut methods);
+
+    let mut analyzer = Analyzer {
+        source: source_code,
+        lines: source_code.lines().collect(),
+        spec,
+        fields: HashMap::new(),
+        flows: Vec::new(),
+    };
+    for pass in 0..FIELD_PASSES {
+        analyzer.flows.clear();
+        for method in &methods {
+            let mut scope = Scope::default();
+            if let Some(parameters) = method.child_by_field_name("parameters") {
+                for parameter in named_children(parameters) {
+                    if let Some(name) = parameter.child_by_field_name("name") {
+                        scope.declared.insert(analyzer.text(name).to_string());
+                    }
+                }
+            }
+            if let Some(body) = method.child_by_field_name("body") {
+                analyzer.visit(body, &mut scope);
+            }
+        }
+        if pass + 1 < FIELD_PASSES && analyzer.fields.is_empty() {
+            break;
+        }
+    }
+    Ok(analyzer.flows)
+}
+
+/// 메서드 호출 하나 (인자는 소스 텍스트 그대로)
+#[derive(Debug, Clone)]
+pub struct CallSite {
+    pub method: String,
+    pub arguments: Vec<String>,
+    pub step: FlowStep,
+}
+
+/// 이름이 `methods` 중 하나인 호출을 모두 찾는다.
+pub fn find_calls_in_file(path: &Path, methods: &[&str]) -> Result<Vec<CallSite>> {
+    let source_code = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read source file: {}", e))?;
+    let tree = parse(&source_code)?;
+    let lines: Vec<&str> = source_code.lines().collect();
+    let mut calls = Vec::new();
+    collect_calls(tree.root_node(), &source_code, &lines, methods, &mut calls);
+    Ok(calls)
+}
+
+fn collect_calls(node: Node, source: &str, lines: &[&str], methods: &[&str], calls: &mut Vec<CallSite>) {
+    if node.kind() == "method_invocation" {
+        let method = node.child_by_field_name("name").map(|n| node_text(n, source)).unwrap_or("");
+        if methods.contains(&method) {
+            calls.push(CallSite {
+                method: method.to_string(),
+                arguments: node.child_by_field_name("arguments")
+                    .map(named_children)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|a| node_text(a, source).to_string())
+                    .collect(),
+                step: line_step(node, lines),
+            });
+        }
+    }
+    for child in named_children(node) {
+        collect_calls(child, source, lines, methods, calls);
+    }
+}
+
+/// Java 소스를 파싱한다.
+pub fn parse(source_code: &str) -> Result<Tree> {
+    let mut parser = Parser::new();
+    parser.set_language(language())
+        .map_err(|e| anyhow::anyhow!("Failed to load Java parser: {}", e))?;
+    parser.parse(source_code, None)
+        .ok_or_else(|| anyhow::anyhow!("Failed to parse source code"))
+}
+
+/// 파일의 변수 선언 중 문자열로 계산되는 값 (상수 이름 → 값)
+pub fn string_constants(root: Node, source: &str) -> HashMap<String, String> {
+    let mut declarations = Vec::new();
+    collect_string_declarations(root, source, &mut declarations);
+    // 상수가 다른 상수를 참조할 수 있으므로 값이 늘지 않을 때까지 반복한다
+    let mut constants = HashMap::new();
+    loop {
+        let known = constants.len();
+        for (name, value) in &declarations {
+            if !constants.contains_key(name) {
+                if let Some(value) = eval_string(*value, source, &constants) {
+                    constants.insert(name.clone(), value);
+                }
+            }
+        }
+        if constants.len() == known {
+            break;
+        }
+    }
+    constants
+}
+
+/// 권한 이름 인자: 문자열 상수 또는 `Manifest.permission.X`. null이면 None
+pub fn eval_permission(node: Node, source: &str, constants: &HashMap<String, String>) -> Option<String> {
+    if node.kind() == "null_literal" {
+        return None;
+    }
+    if let Some(value) = eval_string(node, source, constants) {
+        return Some(value);
+    }
+    let text = node_text(node, source);
+    match text.rsplit_once("Manifest.permission.") {
+        Some((_, name)) => Some(format!("android.permission.{}", name)),
+        None => Some(text.to_string()),
+    }
+}
+
+fn collect_string_declarations<'t>(node: Node<'t>, source: &str, declarations: &mut Vec<(String, Node<'t>)>) {
+    if node.kind() == "variable_declarator" {
+        if let (Some(name), Some(value)) = (node.child_by_field_name("name"), node.child_by_field_name("value")) {
+            declarations.push((node_text(name, source).to_string(), value));
+        }
+    }
+    for child in named_children(node) {
+        collect_string_declarations(child, source, declarations);
+    }
+}
+
+/// 문자열 리터럴, 상수, 둘의 `+` 연결을 계산한다.
+pub fn eval_string(node: Node, source: &str, constants: &HashMap<String, String>) -> Option<String> {
+    match node.kind() {
+        "string_literal" => Some(node_text(node, source).trim_matches('"').to_string()),
+        "identifier" => constants.get(node_text(node, source)).cloned(),
+        "field_access" => node.child_by_field_name("field")
+            .and_then(|field| constants.get(node_text(field, source)).cloned()),
+        "parenthesized_expression" => node.named_child(0).and_then(|n| eval_string(n, source, constants)),
+        "binary_expression" => {
+            let left = eval_string(node.child_by_field_name("left")?, source, constants)?;
+            let right = eval_string(node.child_by_field_name("right")?, source, constants)?;
+            Some(left + &right)
+        }
+        _ => None,
+    }
+}
+
+fn collect_methods<'t>(node: Node<'t>, methods: &mut Vec<Node<'t>>) {
+    if matches!(node.kind(), "method_declaration" | "constructor_declaration") {
+        methods.push(node);
+    }
+    for child in named_children(node) {
+        collect_methods(child, methods);
+    }
+}
+
+pub fn named_children(node: Node) -> Vec<Node> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).collect()
+}
+
+pub fn node_text<'s>(node: Node, source: &'s str) -> &'s str {
+    node.utf8_text(source.as_bytes()).unwrap_or("")
+}
+
+/// 노드가 시작하는 줄
+fn line_step(node: Node, lines: &[&str]) -> FlowStep {
+    let row = node.start_position().row;
+    let code = lines.get(row).map(|l| l.trim()).unwrap_or("");
+    let code = if code.chars().count() > MAX_CODE_LENGTH {
+        format!("{}…", code.chars().take(MAX_CODE_LENGTH).collect::<String>())
+    } else {
+        code.to_string()
+    };
+    FlowStep { line: row + 1, code }
+}
+
+#[derive(Default)]
+struct Scope {
+    /// 이 메서드에서 선언한 이름 (필드를 가린다)
+    declared: HashSet<String>,
+    locals: HashMap<String, Taint>,
+}
+
+struct Analyzer<'s, 'a> {
+    source: &'s str,
+    lines: Vec<&'s str>,
+    spec: &'a TaintSpec<'a>,
+    fields: HashMap<String, Taint>,
+    flows: Vec<TaintFlow>,
+}
+
+impl Analyzer<'_, '_> {
+    fn text(&self, node: Node) -> &str {
+        node_text(node, self.source)
+    }
+
+    fn step(&self, node: Node) -> FlowStep {
+        line_step(node, &self.lines)
+    }
+
+    fn visit(&mut self, node: Node, scope: &mut Scope) {
+        match node.kind() {
+            // 중첩된 클래스의 메서드는 따로 분석한다
+            "method_declaration" | "constructor_declaration" | "class_body" => return,
+            "local_variable_declaration" => {
+                for declarator in named_children(node).into_iter().filter(|n| n.kind() == "variable_declarator") {
+                    let Some(name) = declarator.child_by_field_name("name") else {
+                        continue;
+                    };
+                    let name = self.text(name).to_string();
+                    scope.declared.insert(name.clone());
+                    if let Some(value) = declarator.child_by_field_name("value") {
+                        self.visit(value, scope);
+                        if let Some(taint) = self.eval(value, scope) {
+                            let taint = self.extend(taint, declarator);
+                            scope.locals.insert(name, taint);
+                        }
+                    }
+                }
+                return;
+            }
+            "assignment_expression" => {
+                if let (Some(left), Some(right)) = (node.child_by_field_name("left"), node.child_by_field_name("right")) {
+                    self.visit(right, scope);
+                    if let Some(taint) = self.eval(right, scope) {
+                        let taint = self.extend(taint, node);
+                        self.assign(left, taint, scope);
+                    }
+                }
+                return;
+            }
+            "enhanced_for_statement" => {
+                if let (Some(name), Some(value)) = (node.child_by_field_name("name"), node.child_by_field_name("value")) {
+                    let name = self.text(name).to_string();
+                    scope.declared.insert(name.clone());
+                    if let Some(taint) = self.eval(value, scope) {
+                        let taint = self.extend(taint, node);
+                        scope.locals.insert(name, taint);
+                    }
+                }
+            }
+            "method_invocation" => self.check_sink(node, scope),
+            _ => {}
+        }
+        for child in named_children(node) {
+            self.visit(child, scope);
+        }
+    }
+
+    fn assign(&mut self, left: Node, taint: Taint, scope: &mut Scope) {
+        match left.kind() {
+            "identifier" => {
+                let name = self.text(left).to_string();
+                if scope.declared.contains(&name) {
+                    scope.locals.insert(name, taint);
+                } else {
+                    self.fields.insert(name, taint);
+                }
+            }
+            "field_access" if left.child_by_field_name("object").is_some_and(|o| o.kind() == "this") => {
+                if let Some(field) = left.child_by_field_name("field") {
+                    self.fields.insert(self.text(field).to_string(), taint);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn extend(&self, mut taint: Taint, node: Node) -> Taint {
+        let step = self.step(node);
+        if taint.source != step && !taint.steps.contains(&step) {
+            taint.steps.push(step);
+        }
+        taint
+    }
+
+    fn check_sink(&mut self, node: Node, scope: &Scope) {
+        let Some(name) = node.child_by_field_name("name") else {
+            return;
+        };
+        let method = self.text(name).to_string();
+        if !self.spec.sinks.contains(&method.as_str()) {
+            return;
+        }
+        let Some(arguments) = node.child_by_field_name("arguments") else {
+            return;
+        };
+        for argument in named_children(arguments) {
+            if let Some(taint) = self.eval(argument, scope) {
+                let sink = self.step(node);
+                let duplicate = self.flows.iter()
+                    .any(|f| f.sink == sink && f.source == taint.source);
+                if !duplicate {
+                    self.flows.push(TaintFlow {
+                        key: taint.key,
+                        source_method: taint.source_method,
+                        source: taint.source,
+                        steps: taint.steps,
+                        sink_method: method.clone(),
+                        sink,
+                    });
+                }
+                return;
+            }
+        }
+    }
+
+    /// 식의 값이 오염되었는지 (오염 값 자체이거나 그대로 전달된 값인지)
+    fn eval(&self, node: Node, scope: &Scope) -> Option<Taint> {
+        match node.kind() {
+            "identifier" => {
+                let name = self.text(node);
+                match scope.locals.get(name) {
+                    Some(taint) => Some(taint.clone()),
+                    None if !scope.declared.contains(name) => self.fields.get(name).cloned(),
+                    None => None,
+                }
+            }
+            "field_access" => {
+                let object = node.child_by_field_name("object")?;
+                let field = node.child_by_field_name("field")?;
+                (object.kind() == "this").then(|| self.fields.get(self.text(field)).cloned()).flatten()
+            }
+            "parenthesized_expression" => node.named_child(0).and_then(|n| self.eval(n, scope)),
+            "cast_expression" => node.child_by_field_name("value").and_then(|n| self.eval(n, scope)),
+            // 문자열 연결 ("javascript:" + value)
+            "binary_expression" => node.child_by_field_name("left")
+                .and_then(|n| self.eval(n, scope))
+                .or_else(|| node.child_by_field_name("right").and_then(|n| self.eval(n, scope))),
+            "ternary_expression" => node.child_by_field_name("consequence")
+                .and_then(|n| self.eval(n, scope))
+                .or_else(|| node.child_by_field_name("alternative").and_then(|n| self.eval(n, scope))),
+            "method_invocation" => {
+                let method = self.text(node.child_by_field_name("name")?);
+                let arguments: Vec<Node> = node.child_by_field_name("arguments")
+                    .map(named_children)
+                    .unwrap_or_default();
+                let key = arguments.first()
+                    .filter(|a| a.kind() == "string_literal")
+                    .map(|a| self.text(*a).trim_matches('"').to_string());
+
+                if self.spec.sources.contains(&method) {
+                    return Some(Taint {
+                        key,
+                        source_method: method.to_string(),
+                        source: self.step(node),
+                        steps: Vec::new(),
+                    });
+                }
+                if self.spec.passthrough.contains(&method) {
+                    if let Some(taint) = arguments.iter().find_map(|a| self.eval(*a, scope)) {
+                        return Some(taint);
+                    }
+                }
+                // 오염된 객체에서 꺼낸 값 (bundle.getParcelable("x"), uri.getQueryParameter("u"))
+                let mut taint = node.child_by_field_name("object").and_then(|o| self.eval(o, scope))?;
+                if taint.key.is_none() {
+                    taint.key = key;
+                }
+                Some(taint)
+            }
+            "object_creation_expression" => {
+                let type_name = self.text(node.child_by_field_name("type")?);
+                let arguments = named_children(node.child_by_field_name("arguments")?);
+                if self.spec.copy_types.contains(&type_name) && arguments.len() == 1 {
+                    self.eval(arguments[0], scope)
+                } else if self.spec.wrapper_types.contains(&type_name) {
+                    arguments.iter().find_map(|a| self.eval(*a, scope))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
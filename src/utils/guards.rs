@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::path::Path;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tree_sitter::Node;
+use crate::manifest::Component;
+use crate::utils::dataflow::{eval_permission, named_children, node_text, parse, string_constants};
+use crate::utils::provider::PROVIDER_METHODS;
+
+const ACTIVITY_ENTRY_POINTS: &[&str] = &["onCreate", "onStart", "onResume", "onNewIntent", "onPostCreate"];
+const SERVICE_ENTRY_POINTS: &[&str] = &[
+    "onCreate",
+    "onStartCommand",
+    "onStart",
+    "onBind",
+    "onHandleIntent",
+    "onHandleWork",
+    "onTransact",
+    "handleMessage",
+];
+const RECEIVER_ENTRY_POINTS: &[&str] = &["onReceive"];
+
+/// 호출자의 권한을 확인하는 메서드
+const PERMISSION_CHECKS: &[&str] = &[
+    "checkCallingPermission",
+    "checkCallingOrSelfPermission",
+    "enforceCallingPermission",
+    "enforceCallingOrSelfPermission",
+    "checkPermission",
+    "enforcePermission",
+    "checkCallingUriPermission",
+    "enforceCallingUriPermission",
+];
+
+/// 호출자를 알려주는 메서드 (비교할 때만 확인으로 본다)
+const CALLER_METHODS: &[&str] = &["getCallingPackage", "getCallingActivity", "getCallingUid", "getCallingPid"];
+
+/// 호출자 서명을 확인하는 메서드
+const SIGNATURE_CHECKS: &[&str] = &["checkSignatures", "hasSigningCertificate"];
+
+const COMPARISONS: &[&str] = &["equals", "equalsIgnoreCase", "contains", "startsWith", "endsWith", "matches", "compare"];
+
+const MAX_CODE_LENGTH: usize = 120;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GuardKind {
+    /// checkCallingPermission 등
+    Permission,
+    /// getCallingPackage() 등의 비교
+    Caller,
+    /// checkSignatures, 서명 인증서 비교
+    Signature,
+}
+
+/// 컴포넌트 진입점에서 호출자를 확인하는 코드 하나
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermissionGuard {
+    pub kind: GuardKind,
+    /// 확인이 있는 진입점 (도우미 메서드 안이면 `onCreate → isTrusted`)
+    pub entry_point: String,
+    /// 호출한 메서드
+    pub method: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permission: Option<String>,
+    pub line: usize,
+    pub code: String,
+}
+
+impl PermissionGuard {
+    /// `checkCallingPermission(com.example.READ) in onCreate() at line 12`
+    pub fn describe(&self) -> String {
+        let call = match &self.permission {
+            Some(permission) => format!("{}({})", self.method, permission),
+            None => format!("{}()", self.method),
+        };
+        format!("{} in {}() at line {}", call, self.entry_point, self.line)
+    }
+}
+
+/// 컴포넌트 타입별 진입점
+fn entry_points(component_type: &str) -> &'static [&'static str] {
+    match component_type {
+        "activity" => ACTIVITY_ENTRY_POINTS,
+        "service" => SERVICE_ENTRY_POINTS,
+        "receiver" => RECEIVER_ENTRY_POINTS,
+        "provider" => PROVIDER_METHODS,
+        _ => &[],
+    }
+}
+
+/// 컴포넌트 소스의 진입점과 진입점이 부르는 같은 파일의 메서드에서 호출자 확인을 찾는다.
+pub fn find_guards(component: &Component, path: &Path) -> Result<Vec<PermissionGuard>> {
+    let source = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read source file: {}", e))?;
+    let tree = parse(&source)?;
+    let root = tree.root_node();
+    let constants = string_constants(root, &source);
+
+    let mut methods: HashMap<&str, Vec<Node>> = HashMap::new();
+    collect_methods(root, &source, &mut methods);
+
+    let entries = entry_points(&component.component_type);
+    let mut scopes = Vec::new();
+    for entry in entries {
+        for method in methods.get(entry).into_iter().flatten() {
+            scopes.push((entry.to_string(), *method));
+            // 진입점에서 바로 부르는 도우미 메서드 (한 단계)
+            let mut calls = Vec::new();
+            collect_invocations(*method, &mut calls);
+            for call in calls {
+                let local = call.child_by_field_name("object").is_none_or(|o| node_text(o, &source) == "this");
+                let name = call.child_by_field_name("name").map(|n| node_text(n, &source)).unwrap_or("");
+                if !local || entries.contains(&name) {
+                    continue;
+                }
+                for helper in methods.get(name).into_iter().flatten() {
+                    let label = format!("{} → {}", entry, name);
+                    if !scopes.iter().any(|(l, m)| *l == label && m == helper) {
+                        scopes.push((label, *helper));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut guards: Vec<PermissionGuard> = Vec::new();
+    for (label, scope) in scopes {
+        let mut calls = Vec::new();
+        collect_invocations(scope, &mut calls);
+        for call in calls {
+            let Some(guard) = guard_at(call, scope, &label, &source, &constants) else {
+                continue;
+            };
+            if !guards.iter().any(|g| g.line == guard.line && g.method == guard.method) {
+                guards.push(guard);
+            }
+        }
+    }
+    guards.sort_by_key(|g| g.line);
+    Ok(guards)
+}
+
+fn guard_at(call: Node, scope: Node, entry_point: &str, source: &str, constants: &HashMap<String, String>) -> Option<PermissionGuard> {
+    let method = call.child_by_field_name("name").map(|n| node_text(n, source))?;
+    let arguments = call.child_by_field_name("arguments").map(named_children).unwrap_or_default();
+    let arguments_text = call.child_by_field_name("arguments").map(|n| node_text(n, source)).unwrap_or("");
+
+    let (kind, permission) = if PERMISSION_CHECKS.contains(&method) {
+        // checkPermission(permission, pid, uid)은 호출자의 pid/uid를 넘길 때만 호출자 확인이다
+        if matches!(method, "checkPermission" | "enforcePermission") && !arguments_text.contains("Calling") {
+            return None;
+        }
+        let permission = if method.contains("Uri") {
+            None
+        } else {
+            arguments.first().and_then(|a| eval_permission(*a, source, constants))
+        };
+        (GuardKind::Permission, permission)
+    } else if SIGNATURE_CHECKS.contains(&method)
+        || (method == "getPackageInfo" && (arguments_text.contains("GET_SIGNATURES") || arguments_text.contains("GET_SIGNING_CERTIFICATES")))
+    {
+        (GuardKind::Signature, None)
+    } else if CALLER_METHODS.contains(&method) && is_compared(call, scope, source, 0) {
+        (GuardKind::Caller, None)
+    } else {
+        return None;
+    };
+
+    let code = node_text(call, source).split_whitespace().collect::<Vec<_>>().join(" ");
+    Some(PermissionGuard {
+        kind,
+        entry_point: entry_point.to_string(),
+        method: method.to_string(),
+        permission,
+        line: call.start_position().row + 1,
+        code: code.chars().take(MAX_CODE_LENGTH).collect(),
+    })
+}
+
+/// 값이 비교나 조건에 쓰이는지. 지역 변수에 넣으면 그 변수가 쓰이는 곳을 본다.
+fn is_compared(node: Node, scope: Node, source: &str, depth: usize) -> bool {
+    let mut current = node;
+    while let Some(parent) = current.parent() {
+        match parent.kind() {
+            "binary_expression" => {
+                let operator = parent.child_by_field_name("operator").map(|o| node_text(o, source)).unwrap_or("");
+                if matches!(operator, "==" | "!=") {
+                    return true;
+                }
+            }
+            "method_invocation" => {
+                let name = parent.child_by_field_name("name").map(|n| node_text(n, source)).unwrap_or("");
+                if COMPARISONS.contains(&name) {
+                    return true;
+                }
+            }
+            "if_statement" | "while_statement" | "ternary_expression" | "switch_expression" | "switch_statement" => {
+                let condition = parent.child_by_field_name("condition").or_else(|| parent.child_by_field_name("value"));
+                if condition.is_some_and(|c| c.id() == current.id()) {
+                    return true;
+                }
+            }
+            "variable_declarator" | "assignment_expression" => {
+                let target = parent.child_by_field_name("name").or_else(|| parent.child_by_field_name("left"));
+                return depth < 2 && target.is_some_and(|t| variable_compared(node_text(t, source), t, scope, source, depth + 1));
+            }
+            "expression_statement" | "block" | "method_declaration" | "lambda_expression" | "return_statement" => return false,
+            _ => {}
+        }
+        current = parent;
+    }
+    false
+}
+
+fn variable_compared(name: &str, declaration: Node, scope: Node, source: &str, depth: usize) -> bool {
+    let mut uses = Vec::new();
+    collect_identifiers(scope, name, source, &mut uses);
+    uses.into_iter()
+        .filter(|u| u.id() != declaration.id())
+        .any(|u| is_compared(u, scope, source, depth))
+}
+
+fn collect_identifiers<'t>(node: Node<'t>, name: &str, source: &str, uses: &mut Vec<Node<'t>>) {
+    if node.kind() == "identifier" && node_text(node, source) == name {
+        uses.push(node);
+    }
+    for child in named_children(node) {
+        collect_identifiers(child, name, source, uses);
+    }
+}
+
+fn collect_methods<'t, 's>(node: Node<'t>, source: &'s str, methods: &mut HashMap<&'s str, Vec<Node<'t>>>) {
+    if node.kind() == "method_declaration" {
+        if let Some(name) = node.child_by_field_name("name") {
+            methods.entry(node_text(name, source)).or_default().push(node);
+        }
+    }
+    for child in named_children(node) {
+        collect_methods(child, source, methods);
+    }
+}
+
+fn collect_invocations<'t>(node: Node<'t>, calls: &mut Vec<Node<'t>>) {
+    if node.kind() == "method_invocation" {
+        calls.push(node);
+    }
+    for child in named_children(node) {
+        collect_invocations(child, calls);
+    }
+}
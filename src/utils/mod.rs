@@ -1,4 +1,8 @@
 pub mod adb;
+pub mod dataflow;
+pub mod guards;
+pub mod provider;
+pub mod receivers;
 pub mod source;
 
  
\ No newline at end of file
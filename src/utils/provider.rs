@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::path::Path;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tree_sitter::Node;
+use crate::manifest::component::FileProviderPath;
+use crate::manifest::Component;
+use crate::utils::dataflow::{eval_string, named_children, node_text, parse, string_constants};
+
+/// 컴포넌트 하나에서 만들 content URI의 최대 개수
+const MAX_CONTENT_URIS: usize = 16;
+
+/// ContentProvider에서 재정의하면 외부에 열리는 메서드
+pub const PROVIDER_METHODS: &[&str] = &[
+    "query",
+    "insert",
+    "bulkInsert",
+    "update",
+    "delete",
+    "getType",
+    "call",
+    "openFile",
+    "openAssetFile",
+    "openTypedAssetFile",
+];
+
+/// 파일을 여는 메서드
+pub const FILE_METHODS: &[&str] = &["openFile", "openAssetFile", "openTypedAssetFile"];
+
+/// 경로를 정규화하거나 `..`을 거르는 호출
+const PATH_CHECKS: &[&str] = &["getCanonicalPath", "getCanonicalFile", "toRealPath", "normalize", "\"..\""];
+
+/// `UriMatcher.addURI` 호출 하나
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UriMatcherPath {
+    /// 상수를 따라가도 값을 알 수 없으면 None
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authority: Option<String>,
+    pub path: String,
+    pub line: usize,
+}
+
+/// 파일을 여는 메서드 구현 하나
+#[derive(Debug, Clone)]
+pub struct FileMethod {
+    pub name: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    /// 경로를 정규화하거나 `..`을 확인하는 코드가 있는지
+    pub checks_path: bool,
+}
+
+/// provider 소스에서 찾은 정보
+#[derive(Debug, Clone, Default)]
+pub struct ProviderSource {
+    pub uri_paths: Vec<UriMatcherPath>,
+    /// 소스에 구현된 ContentProvider 메서드
+    pub implemented: Vec<String>,
+    pub file_methods: Vec<FileMethod>,
+}
+
+impl ProviderSource {
+    pub fn implements(&self, method: &str) -> bool {
+        self.implemented.iter().any(|m| m == method)
+    }
+
+    /// 줄을 포함하는 파일 메서드
+    pub fn file_method_at(&self, line: usize) -> Option<&FileMethod> {
+        self.file_methods.iter().find(|m| m.start_line <= line && line <= m.end_line)
+    }
+}
+
+/// provider의 Java 소스에서 UriMatcher 경로와 구현된 메서드를 찾는다.
+pub fn analyze_provider_source(path: &Path) -> Result<ProviderSource> {
+    let source_code = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read source file: {}", e))?;
+    let tree = parse(&source_code)?;
+    let constants = string_constants(tree.root_node(), &source_code);
+
+    let mut result = ProviderSource::default();
+    collect_provider_info(tree.root_node(), &source_code, &constants, &mut result);
+    Ok(result)
+}
+
+fn collect_provider_info(node: Node, source: &str, constants: &HashMap<String, String>, result: &mut ProviderSource) {
+    match node.kind() {
+        "method_declaration" => {
+            let name = node.child_by_field_name("name").map(|n| node_text(n, source)).unwrap_or("");
+            if PROVIDER_METHODS.contains(&name) && !result.implements(name) {
+                result.implemented.push(name.to_string());
+            }
+            if FILE_METHODS.contains(&name) {
+                let body = node_text(node, source);
+                result.file_methods.push(FileMethod {
+                    name: name.to_string(),
+                    start_line: node.start_position().row + 1,
+                    end_line: node.end_position().row + 1,
+                    checks_path: PATH_CHECKS.iter().any(|check| body.contains(check)),
+                });
+            }
+        }
+        "method_invocation" if node.child_by_field_name("name").is_some_and(|n| node_text(n, source) == "addURI") => {
+            let arguments = node.child_by_field_name("arguments").map(named_children).unwrap_or_default();
+            if let [authority, path, ..] = arguments.as_slice() {
+                // path가 null이면 authority 자체와 맞는다
+                let path = if path.kind() == "null_literal" {
+                    Some(String::new())
+                } else {
+                    eval_string(*path, source, constants)
+                };
+                if let Some(path) = path {
+                    result.uri_paths.push(UriMatcherPath {
+                        authority: eval_string(*authority, source, constants),
+                        path,
+                        line: node.start_position().row + 1,
+                    });
+                }
+            }
+        }
+        _ => {}
+    }
+    for child in named_children(node) {
+        collect_provider_info(child, source, constants, result);
+    }
+}
+
+/// UriMatcher 경로를 구체적인 경로로 바꾼다 (`#`은 숫자, `*`는 아무 문자열).
+fn example_path(path: &str) -> String {
+    path.trim_start_matches('/')
+        .split('/')
+        .map(|segment| match segment {
+            "#" => "1",
+            "*" => "example",
+            other => other,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// FileProvider가 경로 항목으로 내줄 URI. 앱의 기본 SharedPreferences가 공유 범위 안이면 그 파일을 가리킨다.
+pub fn file_provider_uri(authority: &str, entry: &FileProviderPath, package: &str) -> Option<String> {
+    let shared_dir = entry.shared_dir(package)?;
+    let preferences = format!("/data/data/{}/shared_prefs/{}_preferences.xml", package, package);
+    let shared_prefix = format!("{}/", shared_dir.trim_end_matches('/'));
+    let relative = preferences.strip_prefix(&shared_prefix).unwrap_or("example");
+    Some(format!("content://{}/{}/{}", authority, entry.name, relative))
+}
+
+/// provider의 authorities와 UriMatcher 경로, FileProvider 경로 항목으로 content URI를 만든다.
+///
+/// 경로의 authority를 알 수 없으면 모든 authority에 붙인다. 경로가 없으면 `content://<authority>`만 만든다.
+pub fn content_uris(component: &Component, source: Option<&ProviderSource>) -> Vec<String> {
+    let Some(provider) = &component.provider else {
+        return Vec::new();
+    };
+    let mut uris = Vec::new();
+    for authority in &provider.authorities {
+        let paths: Vec<&UriMatcherPath> = source
+            .map(|s| s.uri_paths.iter()
+                .filter(|p| p.authority.as_ref().is_none_or(|a| a == authority))
+                .collect())
+            .unwrap_or_default();
+        let file_uris: Vec<String> = provider.file_paths.iter()
+            .filter_map(|entry| file_provider_uri(authority, entry, &component.package))
+            .collect();
+        if paths.is_empty() && file_uris.is_empty() {
+            uris.push(format!("content://{}", authority));
+        }
+        for uri in file_uris {
+            if !uris.contains(&uri) {
+                uris.push(uri);
+            }
+        }
+        for path in paths {
+            let path = example_path(&path.path);
+            let uri = if path.is_empty() {
+                format!("content://{}", authority)
+            } else {
+                format!("content://{}/{}", authority, path)
+            };
+            if !uris.contains(&uri) {
+                uris.push(uri);
+            }
+        }
+    }
+    uris.truncate(MAX_CONTENT_URIS);
+    uris
+}
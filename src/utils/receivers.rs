@@ -0,0 +1,350 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use anyhow::Result;
+use tracing::{debug, warn};
+use tree_sitter::Node;
+use crate::manifest::component::{Component, DataElement, DynamicRegistration, IntentFilter};
+use crate::utils::dataflow::{eval_permission, eval_string, named_children, node_text, parse, string_constants};
+use crate::utils::source::SourceFileCache;
+
+const NOT_EXPORTED: &str = "RECEIVER_NOT_EXPORTED";
+const EXPORTED: &str = "RECEIVER_EXPORTED";
+
+/// 같은 앱의 소스에서 `registerReceiver`로 등록되는 receiver를 찾아 컴포넌트로 만든다.
+///
+/// 앱마다 그 앱 컴포넌트의 소스 디렉토리를 검색하고, 패키지와 모듈 정보는 같은 앱의 컴포넌트에서 가져온다.
+pub fn find_dynamic_receivers(components: &[Component]) -> Vec<Component> {
+    let mut packages: BTreeMap<&str, Vec<&Component>> = BTreeMap::new();
+    for component in components.iter().filter(|c| c.dynamic.is_none()) {
+        packages.entry(component.package.as_str()).or_default().push(component);
+    }
+
+    let mut receivers = Vec::new();
+    for (package, group) in packages {
+        let mut cache = SourceFileCache::for_components(group.iter().copied());
+        if let Err(e) = cache.scan_roots() {
+            warn!("Failed to scan source files of {}: {}", package, e);
+            continue;
+        }
+        for file in cache.java_files() {
+            match receivers_in_file(&file, group[0]) {
+                Ok(found) => receivers.extend(found),
+                Err(e) => warn!("Failed to find registered receivers in {}: {}", file.display(), e),
+            }
+        }
+    }
+    receivers
+}
+
+fn receivers_in_file(path: &Path, app: &Component) -> Result<Vec<Component>> {
+    let source = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read source file: {}", e))?;
+    if !source.contains("registerReceiver") {
+        return Ok(Vec::new());
+    }
+    let tree = parse(&source)?;
+    let root = tree.root_node();
+    let mut scanner = Scanner {
+        source: &source,
+        path,
+        app,
+        root,
+        constants: string_constants(root, &source),
+        package: named_children(root).into_iter()
+            .find(|n| n.kind() == "package_declaration")
+            .and_then(|n| n.named_child(0))
+            .map(|n| node_text(n, &source).to_string()),
+        classes: Vec::new(),
+        anonymous: HashMap::new(),
+        receivers: Vec::new(),
+    };
+    scanner.visit(root, None);
+    Ok(scanner.receivers)
+}
+
+struct Scanner<'a, 't> {
+    source: &'a str,
+    path: &'a Path,
+    /// 패키지와 모듈 정보를 가져올 같은 앱의 컴포넌트
+    app: &'a Component,
+    root: Node<'t>,
+    constants: HashMap<String, String>,
+    package: Option<String>,
+    classes: Vec<String>,
+    /// 등록한 클래스별 이름 없는 receiver 수
+    anonymous: HashMap<String, usize>,
+    receivers: Vec<Component>,
+}
+
+impl<'t> Scanner<'_, 't> {
+    fn text(&self, node: Node) -> &str {
+        node_text(node, self.source)
+    }
+
+    fn visit(&mut self, node: Node<'t>, scope: Option<Node<'t>>) {
+        let mut scope = scope;
+        let mut class = false;
+        match node.kind() {
+            "class_declaration" | "enum_declaration" | "record_declaration" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    self.classes.push(self.text(name).to_string());
+                    class = true;
+                }
+            }
+            "method_declaration" | "constructor_declaration" => scope = Some(node),
+            "method_invocation" => self.check_call(node, scope),
+            _ => {}
+        }
+        for child in named_children(node) {
+            self.visit(child, scope);
+        }
+        if class {
+            self.classes.pop();
+        }
+    }
+
+    fn check_call(&mut self, node: Node<'t>, scope: Option<Node<'t>>) {
+        if node.child_by_field_name("name").is_none_or(|n| self.text(n) != "registerReceiver") {
+            return;
+        }
+        let object = node.child_by_field_name("object").map(|o| self.text(o)).unwrap_or("");
+        // LocalBroadcastManager는 앱 밖에서 보낼 수 없다
+        if object.contains("LocalBroadcast") {
+            return;
+        }
+        let mut arguments = node.child_by_field_name("arguments").map(named_children).unwrap_or_default();
+        // ContextCompat.registerReceiver(context, receiver, filter, ...)
+        if object.ends_with("ContextCompat") && !arguments.is_empty() {
+            arguments.remove(0);
+        }
+        let (receiver, filter) = match arguments.as_slice() {
+            [receiver, filter, ..] => (*receiver, *filter),
+            _ => return,
+        };
+        // (receiver, filter, flags), (receiver, filter, permission, handler[, flags])
+        let (permission, flags) = match arguments.len() {
+            3 => (None, Some(arguments[2])),
+            4 => (Some(arguments[2]), None),
+            _ => (arguments.get(2).copied(), arguments.get(4).copied()),
+        };
+
+        let export_flag = flags.and_then(|flags| {
+            let text = self.resolve_text(flags, scope);
+            if text.contains(NOT_EXPORTED) {
+                Some(NOT_EXPORTED.to_string())
+            } else if text.contains(EXPORTED) {
+                Some(EXPORTED.to_string())
+            } else {
+                None
+            }
+        });
+        let permission = permission.and_then(|p| eval_permission(p, self.source, &self.constants));
+        let line = node.start_position().row + 1;
+        let column = node.start_position().column + 1;
+        let mut intent_filter = IntentFilter { line, column, ..IntentFilter::default() };
+        self.build_filter(filter, scope, &mut intent_filter);
+        if intent_filter.actions.is_empty() {
+            debug!("No action found for the receiver registered at {}:{}", self.path.display(), line);
+        }
+
+        let registered_by = self.qualify(&self.classes.join("."));
+        let name = match self.receiver_class(receiver, scope) {
+            Some(class) => self.qualify(&class),
+            // 익명 클래스 등: 등록한 클래스와 변수 이름으로 부른다.
+            // 변수가 없으면 줄 번호 대신 클래스 안의 순번을 써서 위쪽 코드가 바뀌어도 이름이 유지되게 한다
+            None => match receiver.kind() {
+                "identifier" => format!("{}${}", registered_by, self.text(receiver)),
+                _ => {
+                    let ordinal = self.anonymous.entry(registered_by.clone()).or_default();
+                    *ordinal += 1;
+                    format!("{}$receiver{}", registered_by, ordinal)
+                }
+            },
+        };
+        if self.receivers.iter().any(|r| r.name == name && r.manifest_line == line) {
+            return;
+        }
+
+        let mut component = Component {
+            name: name.clone(),
+            class_name: name,
+            component_type: "receiver".to_string(),
+            exported: export_flag.as_deref() != Some(NOT_EXPORTED),
+            manifest_path: self.path.to_path_buf(),
+            manifest_line: line,
+            manifest_column: column,
+            permissions: permission.into_iter().collect(),
+            intent_filter_permissions: Vec::new(),
+            xml_element: None,
+            xml_normalized: None,
+            intent_filters: vec![intent_filter],
+            tools_node: None,
+            tools_replace: Vec::new(),
+            declared_attributes: Default::default(),
+            merged_from: Vec::new(),
+            provider: None,
+            meta_data: Vec::new(),
+            activity: None,
+            dynamic: Some(DynamicRegistration {
+                registered_by,
+                code: self.text(node).split_whitespace().collect::<Vec<_>>().join(" "),
+                export_flag,
+            }),
+            ..self.app.clone()
+        };
+        component.refresh_intent_data();
+        self.receivers.push(component);
+    }
+
+    /// 패키지 이름을 붙인 클래스 이름 (import된 클래스는 import 경로)
+    fn qualify(&self, class: &str) -> String {
+        if class.contains('.') && class.chars().next().is_some_and(|c| c.is_lowercase()) {
+            return class.to_string();
+        }
+        let import = named_children(self.root).into_iter()
+            .filter(|n| n.kind() == "import_declaration")
+            .map(|n| self.text(n).trim_start_matches("import").trim().trim_end_matches(';').trim().to_string())
+            .find(|path| path.ends_with(&format!(".{}", class)));
+        match (import, &self.package) {
+            (Some(import), _) => import,
+            (None, Some(package)) => format!("{}.{}", package, class),
+            (None, None) => class.to_string(),
+        }
+    }
+
+    /// receiver 인자가 만드는 클래스. 익명 클래스거나 알 수 없으면 None
+    fn receiver_class(&self, node: Node<'t>, scope: Option<Node<'t>>) -> Option<String> {
+        match node.kind() {
+            "object_creation_expression" => {
+                let anonymous = named_children(node).iter().any(|c| c.kind() == "class_body");
+                if anonymous {
+                    return None;
+                }
+                node.child_by_field_name("type").map(|t| self.text(t).to_string())
+            }
+            "this" => Some(self.classes.join(".")),
+            "identifier" => self.values(self.text(node), scope).into_iter()
+                .find_map(|value| self.receiver_class(value, None)),
+            "field_access" => node.child_by_field_name("field")
+                .and_then(|field| self.values(self.text(field), None).into_iter().find_map(|v| self.receiver_class(v, None))),
+            _ => None,
+        }
+    }
+
+    /// action, category 등 문자열 인자
+    fn string_value(&self, node: Node) -> Option<String> {
+        if let Some(value) = eval_string(node, self.source, &self.constants) {
+            return Some(value);
+        }
+        // Intent.ACTION_SCREEN_OFF → android.intent.action.SCREEN_OFF
+        let text = self.text(node);
+        let name = text.strip_prefix("Intent.").or_else(|| text.strip_prefix("android.content.Intent."))?;
+        name.strip_prefix("ACTION_").map(|action| format!("android.intent.action.{}", action))
+    }
+
+    /// IntentFilter 식에서 action, category, data를 모은다.
+    fn build_filter(&self, node: Node<'t>, scope: Option<Node<'t>>, filter: &mut IntentFilter) {
+        match node.kind() {
+            "object_creation_expression" => {
+                let arguments = node.child_by_field_name("arguments").map(named_children).unwrap_or_default();
+                if let Some(action) = arguments.first().and_then(|a| self.string_value(*a)) {
+                    filter.actions.push(action);
+                }
+                if let Some(mime_type) = arguments.get(1).and_then(|a| self.string_value(*a)) {
+                    filter.data.push(DataElement { mime_type: Some(mime_type), ..DataElement::default() });
+                }
+            }
+            "identifier" | "field_access" => {
+                let name = match node.child_by_field_name("field") {
+                    Some(field) => self.text(field),
+                    None => self.text(node),
+                };
+                // 메서드 안에서 만든 필터가 아니면 필드로 보고 파일 전체를 찾는다
+                let scope = scope.filter(|s| !self.values(name, Some(*s)).is_empty());
+                for value in self.values(name, scope) {
+                    self.build_filter(value, None, filter);
+                }
+                let mut calls = Vec::new();
+                collect_calls_on(scope.unwrap_or(self.root), name, self.source, &mut calls);
+                for call in calls {
+                    self.apply_filter_call(call, filter);
+                }
+            }
+            "parenthesized_expression" | "cast_expression" => {
+                if let Some(inner) = node.named_child(node.named_child_count().saturating_sub(1)) {
+                    self.build_filter(inner, scope, filter);
+                }
+            }
+            _ => debug!("Unsupported IntentFilter expression: {}", self.text(node)),
+        }
+    }
+
+    fn apply_filter_call(&self, call: Node, filter: &mut IntentFilter) {
+        let method = call.child_by_field_name("name").map(|n| self.text(n)).unwrap_or("");
+        let arguments = call.child_by_field_name("arguments").map(named_children).unwrap_or_default();
+        let Some(value) = arguments.first().and_then(|a| self.string_value(*a)) else {
+            return;
+        };
+        match method {
+            "addAction" => filter.actions.push(value),
+            "addCategory" => filter.categories.push(value),
+            "addDataScheme" => filter.data.push(DataElement { scheme: Some(value), ..DataElement::default() }),
+            "addDataType" => filter.data.push(DataElement { mime_type: Some(value), ..DataElement::default() }),
+            "addDataAuthority" => filter.data.push(DataElement { host: Some(value), ..DataElement::default() }),
+            "addDataPath" => filter.data.push(DataElement { path: Some(value), ..DataElement::default() }),
+            _ => {}
+        }
+    }
+
+    /// 변수에 선언이나 대입으로 들어간 값. `scope`가 없으면 파일 전체
+    fn values(&self, name: &str, scope: Option<Node<'t>>) -> Vec<Node<'t>> {
+        let mut values = Vec::new();
+        collect_values(scope.unwrap_or(self.root), name, self.source, &mut values);
+        values
+    }
+
+    /// 식별자면 메서드 안에서 대입된 값, 아니면 식 그대로의 코드
+    fn resolve_text(&self, node: Node<'t>, scope: Option<Node<'t>>) -> String {
+        if node.kind() == "identifier" {
+            if let Some(value) = self.values(self.text(node), scope).first() {
+                return self.text(*value).to_string();
+            }
+        }
+        self.text(node).to_string()
+    }
+}
+
+fn collect_values<'t>(node: Node<'t>, name: &str, source: &str, values: &mut Vec<Node<'t>>) {
+    let is_name = |n: Node| {
+        let text = node_text(n, source);
+        text == name || text.strip_prefix("this.") == Some(name)
+    };
+    match node.kind() {
+        "variable_declarator" if node.child_by_field_name("name").is_some_and(is_name) => {
+            values.extend(node.child_by_field_name("value"));
+        }
+        "assignment_expression" if node.child_by_field_name("left").is_some_and(is_name) => {
+            values.extend(node.child_by_field_name("right"));
+        }
+        _ => {}
+    }
+    for child in named_children(node) {
+        collect_values(child, name, source, values);
+    }
+}
+
+/// `name.method(...)` 호출 (`this.name`도 포함)
+fn collect_calls_on<'t>(node: Node<'t>, name: &str, source: &str, calls: &mut Vec<Node<'t>>) {
+    if node.kind() == "method_invocation" {
+        let on_name = node.child_by_field_name("object").is_some_and(|o| {
+            let text = node_text(o, source);
+            text == name || text.strip_prefix("this.") == Some(name)
+        });
+        if on_name {
+            calls.push(node);
+        }
+    }
+    for child in named_children(node) {
+        collect_calls_on(child, name, source, calls);
+    }
+}
@@ -1,30 +1,52 @@
-use std::path::PathBuf;
-use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::collections::HashMap;
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use crate::manifest::Component;
 use tree_sitter::{Parser, Query, QueryCursor};
 use tree_sitter_java::language;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IntentParameter {
     pub name: String,
     pub value: String,
+    #[serde(rename = "type")]
     pub type_: String,
 }
 
 pub struct SourceFileCache {
     files: HashMap<String, Vec<PathBuf>>,
-    manifest_dir: PathBuf,
+    roots: Vec<PathBuf>,
 }
 
 impl SourceFileCache {
-    pub fn new(manifest_path: &PathBuf) -> Self {
+    pub fn new(manifest_path: &Path) -> Self {
         Self {
             files: HashMap::new(),
-            manifest_dir: manifest_path.parent().unwrap().to_path_buf(),
+            roots: vec![manifest_path.parent().unwrap().to_path_buf()],
         }
     }
 
+    /// Gradle 모델에서 얻은 소스 디렉토리가 있으면 그것을, 없으면 매니페스트 디렉토리를 검색 대상으로 한다.
+    pub fn for_component(component: &Component) -> Self {
+        if component.source_roots.is_empty() {
+            return Self::new(&component.manifest_path);
+        }
+        Self {
+            files: HashMap::new(),
+            roots: component.source_roots.clone(),
+        }
+    }
+
+    pub fn scan_roots(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        for root in self.roots.clone() {
+            if root.is_dir() {
+                self.scan_directory(&root)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn scan_directory(&mut self, dir: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
         let entries = std::fs::read_dir(dir)?;
         for entry in entries.filter_map(Result::ok) {
@@ -44,14 +66,52 @@ impl SourceFileCache {
         Ok(())
     }
 
+    /// 모든 컴포넌트의 소스 디렉토리 (중복 제외)
+    pub fn for_components<'a>(components: impl IntoIterator<Item = &'a Component>) -> Self {
+        let mut roots: Vec<PathBuf> = Vec::new();
+        for component in components {
+            for root in Self::for_component(component).roots {
+                if !roots.iter().any(|r| root.starts_with(r)) {
+                    roots.retain(|r| !r.starts_with(&root));
+                    roots.push(root);
+                }
+            }
+        }
+        Self {
+            files: HashMap::new(),
+            roots,
+        }
+    }
+
+    /// 찾은 Java 소스 파일 (경로 순)
+    pub fn java_files(&self) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = self.files.values()
+            .flatten()
+            .filter(|path| path.extension().is_some_and(|e| e.eq_ignore_ascii_case("java")))
+            .cloned()
+            .collect();
+        files.sort();
+        files.dedup();
+        files
+    }
+
     pub fn find_component_file(&self, component: &Component) -> Option<PathBuf> {
-        let component_name = component.name.split('.').last().unwrap_or(&component.name);
+        let component_name = component.name.split('.').next_back().unwrap_or(&component.name);
         
         // 1. Exact name matching
         if let Some(files) = self.files.get(component_name) {
             if files.len() == 1 {
                 return Some(files[0].clone());
             }
+
+            // 같은 이름의 파일이 여러 개면 패키지 디렉토리 구조가 일치하는 파일
+            let package_path: PathBuf = component.name.split('.').collect();
+            let in_package: Vec<_> = files.iter()
+                .filter(|path| path.with_extension("").ends_with(&package_path))
+                .collect();
+            if in_package.len() == 1 {
+                return Some(in_package[0].clone());
+            }
         }
 
         // 2. Partial name matching
@@ -62,9 +122,9 @@ impl SourceFileCache {
             }
         }
 
-        // Filter files that are under the manifest directory
+        // Filter files that are under the source roots
         let manifest_matches: Vec<_> = matches.into_iter()
-            .filter(|path| path.starts_with(&self.manifest_dir))
+            .filter(|path| self.roots.iter().any(|root| path.starts_with(root)))
             .collect();
 
         if manifest_matches.len() == 1 {
@@ -76,12 +136,11 @@ impl SourceFileCache {
 }
 
 #[allow(dead_code)]
-pub fn find_source_dir(manifest_path: &PathBuf) -> Option<PathBuf> {
+pub fn find_source_dir(manifest_path: &Path) -> Option<PathBuf> {
     let mut cache = SourceFileCache::new(manifest_path);
-    let manifest_dir = cache.manifest_dir.clone();
     
     // Scan manifest directory and its subdirectories
-    if let Err(e) = cache.scan_directory(&manifest_dir) {
+    if let Err(e) = cache.scan_roots() {
         eprintln!("Error scanning source files: {}", e);
         return None;
     }
@@ -99,17 +158,17 @@ pub fn find_source_dir(manifest_path: &PathBuf) -> Option<PathBuf> {
 }
 
 pub fn find_source_file(component: &Component, _base_dir: &str) -> Result<PathBuf> {
-    let manifest_path = PathBuf::from(&component.manifest_path);
-    let mut cache = SourceFileCache::new(&manifest_path);
-    let manifest_dir = cache.manifest_dir.clone();
-    
-    // Scan the manifest directory and its subdirectories
-    cache.scan_directory(&manifest_dir)
+    let mut cache = SourceFileCache::for_component(component);
+
+    // Scan the source roots (or the manifest directory) and their subdirectories
+    cache.scan_roots()
         .map_err(|e| anyhow::anyhow!("Failed to scan directory for source files: {}", e))?;
 
     // Try to find the component's source file
-    cache.find_component_file(component)
-        .ok_or_else(|| anyhow::anyhow!("Could not find source file for component: {}", component.name))
+    let found = cache.find_component_file(component);
+    // 동적 receiver의 클래스를 찾지 못하면 (익명/내부 클래스) 등록한 파일
+    let found = found.or_else(|| component.dynamic.as_ref().map(|_| component.manifest_path.clone()));
+    found.ok_or_else(|| anyhow::anyhow!("Could not find source file for component: {}", component.name))
 }
 
 pub fn parse_intent_parameters(source_file: &PathBuf) -> Result<Vec<IntentParameter>> {
@@ -168,7 +227,6 @@ pub fn parse_intent_parameters(source_file: &PathBuf) -> Result<Vec<IntentParame
             // Special case for getData()
             if method_name == "getData" {
                 // Handle getData() case
-                let param_id = format!("data:uri:{}", method_name);
                 parameters.push(IntentParameter {
                     name: "data".to_string(),
                     value: "uri".to_string(),
@@ -216,9 +274,6 @@ pub fn parse_intent_parameters(source_file: &PathBuf) -> Result<Vec<IntentParame
                     type_.clone()
                 };
                 
-                // Create unique identifier for parameter to avoid duplicates
-                // Include method name in the param_id to better handle duplicates
-                let param_id = format!("{}:{}:{}", key, type_, method_name);
                 parameters.push(IntentParameter {
                     name: key,
                     value,
@@ -245,10 +300,7 @@ pub fn intent_parameters_to_adb_args(parameters: &[IntentParameter]) -> Vec<Stri
         
         let arg = match param.type_.as_str() {
             "string" => format!("--es {} {}", param.name, param.value.trim_matches('"')),
-            "int" => format!("--ei {} {}", param.name, param.value),
-            "float" => format!("--ef {} {}", param.name, param.value),
-            "boolean" => format!("--ez {} {}", param.name, param.value),
-            _ => format!("--es {} {}", param.name, param.value),
+            _ => format!("{} {} {}", adb_extra_flag(&param.type_), param.name, param.value),
         };
         
         result.push(arg);
@@ -256,3 +308,14 @@ pub fn intent_parameters_to_adb_args(parameters: &[IntentParameter]) -> Vec<Stri
     
     result
 }
+
+/// extra 타입에 해당하는 `am` 명령어 플래그
+pub fn adb_extra_flag(type_: &str) -> &'static str {
+    match type_ {
+        "int" => "--ei",
+        "long" => "--el",
+        "float" => "--ef",
+        "boolean" => "--ez",
+        _ => "--es",
+    }
+}